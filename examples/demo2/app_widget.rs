@@ -9,7 +9,10 @@ use crate::{
 
 pub struct AppWidget {
     pub tab_index: usize,
-    pub tabs: Vec<Box<dyn tabs::Tab>>,
+    /// Each tab alongside its own scroll offset, so switching tabs and back doesn't reset how far
+    /// the user had scrolled. Owned here (rather than on the `Tab` itself) since it's `AppWidget`,
+    /// not the individual tab, that persists across frames and knows which tab is active.
+    pub tabs: Vec<(Box<dyn tabs::Tab>, u16)>,
 }
 
 impl AppWidget {
@@ -17,20 +20,27 @@ impl AppWidget {
         let tabs: Vec<Box<dyn Tab>> = vec![
             Box::new(tabs::AboutTab),
             Box::new(tabs::EmailTab::new()),
+            Box::new(tabs::RecipeTab::new(0)),
             Box::new(tabs::TracerouteTab::new()),
+            Box::new(tabs::DashboardTab::new()),
             // Box::new(tabs::TextTab),
             // Box::new(tabs::BarsTab),
         ];
 
         AppWidget {
             tab_index: selected_tab,
-            tabs,
+            tabs: tabs.into_iter().map(|tab| (tab, 0)).collect(),
+        }
+    }
+
+    /// Scrolls the currently selected tab by `delta` lines (negative scrolls up), clamped to zero.
+    pub fn scroll_active_tab(&mut self, delta: i16) {
+        if let Some((_, scroll)) = self.tabs.get_mut(self.tab_index) {
+            *scroll = scroll.saturating_add_signed(delta);
         }
     }
-}
 
-impl Widget for AppWidget {
-    fn render(self, area: Rect, buf: &mut Buffer) {
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
         Block::new().bg(styles::APP_BACKGROUND).render(area, buf);
         let area = tui::layout(area, Direction::Vertical, vec![1, 0, 1]);
         self.render_title_bar(area[0], buf);
@@ -45,7 +55,7 @@ impl AppWidget {
 
         Paragraph::new(Span::styled("Ratatui v0.23.0 ", styles::APP_TITLE)).render(area[0], buf);
 
-        let titles = self.tabs.iter().map(|tab| tab.title()).collect_vec();
+        let titles = self.tabs.iter().map(|(tab, _)| tab.title()).collect_vec();
         Tabs::new(titles)
             .style(styles::TABS)
             .highlight_style(styles::TABS_SELECTED)
@@ -54,8 +64,8 @@ impl AppWidget {
     }
 
     fn render_selected_tab(&self, area: Rect, buf: &mut Buffer) {
-        if let Some(tab) = self.tabs.get(self.tab_index) {
-            tab.render(area, buf);
+        if let Some((tab, scroll)) = self.tabs.get(self.tab_index) {
+            tab.render(area, buf, *scroll);
         }
     }
 
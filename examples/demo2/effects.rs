@@ -0,0 +1,179 @@
+use rand::Rng;
+use rand_chacha::rand_core::SeedableRng;
+use ratatui::{buffer::Buffer, prelude::*, widgets::Widget};
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    big_text::{BigTextBuilder, PixelSize},
+    tui,
+};
+
+/// A frame-based animation or transition, applied to a `Buffer` over several frames.
+///
+/// `apply` is called once per frame with the running `frame_count` and returns `true` once the
+/// effect has finished, at which point the caller should drop it.
+pub trait Effect {
+    fn apply(&mut self, frame_count: usize, area: Rect, buf: &mut Buffer) -> bool;
+}
+
+/// delay the start of the animation so it doesn't start immediately
+const DELAY: usize = 300;
+/// higher means more pixels per frame are modified in the animation
+const SPEED_MULTIPLIER: usize = 100;
+/// delay the start of the text animation so it doesn't start immediately after the initial delay
+const TEXT_DELAY: usize = 120;
+
+/// Dissolves the screen into falling, fading embers, with "RATATUI" burning in behind them.
+///
+/// Triggered by pressing `d`. This effect runs for as long as it's active rather than
+/// self-completing, so `apply` always returns `false`.
+#[derive(Debug, Default)]
+pub struct DissolveEffect;
+
+impl Effect for DissolveEffect {
+    fn apply(&mut self, frame_count: usize, area: Rect, buf: &mut Buffer) -> bool {
+        let frame_count = frame_count.saturating_sub(DELAY);
+        if frame_count == 0 {
+            return false;
+        }
+        let mask_buf = &mut Buffer::empty(area);
+        text(frame_count, area, mask_buf);
+        drip(frame_count, area, buf, mask_buf);
+        false
+    }
+}
+
+/// draw "RATATUI" as unstyled big text (used as the dissolve effect's mask)
+fn text(frame_count: usize, area: Rect, buf: &mut Buffer) {
+    let line1 = "RATATUI";
+    let big_text = BigTextBuilder::default()
+        .lines([line1.into()])
+        .pixel_size(PixelSize::Full)
+        .build()
+        .unwrap();
+
+    // the font size is 8x8 for each character and we have 1 line
+    let area = tui::centered_rect(area, line1.width() as u16 * 8, 8);
+    big_text.render(area, buf);
+}
+
+/// Move a bunch of random pixels down one row.
+///
+/// Each pick some random pixels and move them each down one row. This is a very inefficient way to
+/// do this, but it works well enough for this demo.
+fn drip(frame_count: usize, area: Rect, buf: &mut Buffer, mask_buf: &mut Buffer) {
+    // a seeded rng as we have to move the same random pixels each frame
+    let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(10);
+    let pixel_count = frame_count * SPEED_MULTIPLIER;
+    for _ in 0..pixel_count {
+        let src_x = rng.gen_range(0..area.width);
+        let src_y = rng.gen_range(0..area.height - 1);
+        let mask_cell = mask_buf.get_mut(src_x, src_y);
+        let source_cell = buf.get_mut(src_x, src_y);
+        // skip this pixel if it's in the mask
+        let cloned_cell = source_cell.clone();
+        if mask_cell.symbol() != " ".to_string() {
+            source_cell.reset();
+            source_cell
+                .set_symbol(mask_cell.symbol())
+                .set_fg(Color::Rgb(255, 128, 64));
+            continue;
+        }
+        let (dest_x, dest_y) = if rng.gen_ratio(1, 100) {
+            // move the pixel to a random location about 1% of the time
+            (
+                rng.gen_range(src_x.saturating_sub(5)..src_x.saturating_add(5))
+                    .clamp(area.left(), area.right() - 1),
+                area.top(),
+            )
+        } else {
+            // otherwise move the pixel down a row
+            (src_x, src_y.saturating_add(1).min(area.bottom() - 1))
+        };
+        let dest_cell = buf.get_mut(dest_x, dest_y);
+        let mask_cell = mask_buf.get_mut(dest_x, dest_y);
+        if mask_cell.symbol() != " ".to_string() {
+            continue;
+        }
+        // copy the cell to the new location
+        *dest_cell = cloned_cell;
+    }
+}
+
+/// Fades "RATATUI" in from black to full red, then back down to half-brightness red, then
+/// completes.
+#[derive(Debug, Default)]
+pub struct ColorFadeEffect {
+    start_frame: Option<usize>,
+}
+
+impl Effect for ColorFadeEffect {
+    fn apply(&mut self, frame_count: usize, area: Rect, buf: &mut Buffer) -> bool {
+        let start_frame = *self.start_frame.get_or_insert(frame_count);
+        let sub_frame = (frame_count - start_frame).saturating_sub(TEXT_DELAY);
+        if sub_frame == 0 {
+            return false;
+        }
+        // ramp red component brightness up and down 0..256..128
+        let red = if sub_frame < 256 {
+            sub_frame
+        } else {
+            512_usize.saturating_sub(sub_frame).clamp(128, 255)
+        };
+        let color = Color::Rgb(red as u8, 0, 0);
+
+        let line1 = "RATATUI";
+        let big_text = BigTextBuilder::default()
+            .lines([line1.into()])
+            .pixel_size(PixelSize::Full)
+            .style(Style::new().fg(color))
+            .build()
+            .unwrap();
+
+        let area = tui::centered_rect(area, line1.width() as u16 * 8, 8);
+        big_text.render(area, buf);
+        sub_frame >= 512
+    }
+}
+
+/// Wipes `to` over `from` from left to right over `duration` frames, then completes.
+///
+/// Used to transition from the previously selected tab to the newly selected one.
+#[derive(Debug)]
+pub struct WipeEffect {
+    from: Buffer,
+    to: Buffer,
+    duration: usize,
+    start_frame: Option<usize>,
+}
+
+impl WipeEffect {
+    pub fn new(from: Buffer, to: Buffer, duration: usize) -> Self {
+        Self {
+            from,
+            to,
+            duration,
+            start_frame: None,
+        }
+    }
+}
+
+impl Effect for WipeEffect {
+    fn apply(&mut self, frame_count: usize, area: Rect, buf: &mut Buffer) -> bool {
+        let start_frame = *self.start_frame.get_or_insert(frame_count);
+        let elapsed = frame_count - start_frame;
+        let progress = (elapsed as f64 / self.duration as f64).min(1.0);
+        let reveal_width = (area.width as f64 * progress).round() as u16;
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                let source = if x - area.left() < reveal_width {
+                    &self.to
+                } else {
+                    &self.from
+                };
+                *buf.get_mut(x, y) = source.get(x, y).clone();
+            }
+        }
+        progress >= 1.0
+    }
+}
@@ -1,7 +1,10 @@
 use anyhow::Result;
 
 mod app;
+mod app_widget;
 mod colors;
+mod effects;
+mod menu;
 mod tabs;
 mod text;
 mod tui;
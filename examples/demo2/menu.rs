@@ -0,0 +1,138 @@
+use ratatui::{prelude::*, widgets::*};
+
+/// Something a [`Menu`] can list, fuzzy-filter, and let the user select.
+///
+/// The command palette in `app.rs` lists one `MenuItem` per tab, for switching tabs; the rows
+/// inside a tab are not listed as menu items.
+pub trait MenuItem {
+    /// Extra context needed to format the item, shared across every item in a [`Menu`] (e.g. a
+    /// column width computed once from the whole list).
+    type Data;
+
+    /// The text fuzzy-matched against the user's query.
+    fn label(&self) -> &str;
+
+    /// Renders this item as a `Table` row.
+    fn format(&self, data: &Self::Data) -> Row<'_>;
+}
+
+/// A fuzzy-filterable, keyboard-driven selection list: a query string, the items it's filtering,
+/// and a [`TableState`] tracking which of the *filtered* items is highlighted.
+#[derive(Debug)]
+pub struct Menu<T: MenuItem> {
+    items: Vec<T>,
+    data: T::Data,
+    query: String,
+    /// Indices into `items` that currently match `query`, most relevant first.
+    filtered: Vec<usize>,
+    state: TableState,
+}
+
+impl<T: MenuItem> Menu<T> {
+    pub fn new(items: Vec<T>, data: T::Data) -> Self {
+        let mut menu = Self {
+            items,
+            data,
+            query: String::new(),
+            filtered: Vec::new(),
+            state: TableState::default(),
+        };
+        menu.refilter();
+        menu
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    pub fn push_char(&mut self, c: char) {
+        self.query.push(c);
+        self.refilter();
+    }
+
+    pub fn pop_char(&mut self) {
+        self.query.pop();
+        self.refilter();
+    }
+
+    pub fn select_next(&mut self) {
+        let next = match self.state.selected() {
+            Some(i) if i + 1 < self.filtered.len() => i + 1,
+            Some(_) => 0,
+            None => 0,
+        };
+        self.state.select(Some(next));
+    }
+
+    pub fn select_previous(&mut self) {
+        let previous = match self.state.selected() {
+            Some(0) | None => self.filtered.len().saturating_sub(1),
+            Some(i) => i - 1,
+        };
+        self.state.select(Some(previous));
+    }
+
+    /// The item index (into the original, unfiltered `items`) the user currently has highlighted.
+    pub fn selected(&self) -> Option<usize> {
+        self.state
+            .selected()
+            .and_then(|i| self.filtered.get(i).copied())
+    }
+
+    fn refilter(&mut self) {
+        let mut scored: Vec<(usize, i64)> = self
+            .items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| fuzzy_score(&self.query, item.label()).map(|score| (i, score)))
+            .collect();
+        scored.sort_by(|a, b| b.1.cmp(&a.1));
+        self.filtered = scored.into_iter().map(|(i, _)| i).collect();
+        self.state
+            .select((!self.filtered.is_empty()).then_some(0));
+    }
+
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let block = Block::new()
+            .title(format!(" {} ", self.query))
+            .borders(Borders::ALL);
+        let rows = self
+            .filtered
+            .iter()
+            .map(|&i| self.items[i].format(&self.data));
+        let table = Table::new(rows)
+            .widths(&[Constraint::Percentage(100)])
+            .highlight_style(Style::new().bold().reversed())
+            .block(block);
+        Clear.render(area, buf);
+        StatefulWidget::render(table, area, buf, &mut self.state);
+    }
+}
+
+/// Scores `text` against `query` as a case-insensitive subsequence match, returning `None` when
+/// `query` isn't a subsequence of `text` at all.
+///
+/// Consecutive matched characters (and matches near the start of `text`) score higher, so e.g.
+/// querying `"tr"` ranks `"Traceroute"` above `"The Recipe"`.
+fn fuzzy_score(query: &str, text: &str) -> Option<i64> {
+    if query.is_empty() {
+        return Some(0);
+    }
+    let text_lower = text.to_lowercase();
+    let mut chars = text_lower.chars().enumerate();
+    let mut score = 0i64;
+    let mut last_match: Option<usize> = None;
+    for query_char in query.to_lowercase().chars() {
+        let (index, _) = chars.find(|&(_, c)| c == query_char)?;
+        score += 10;
+        if let Some(last) = last_match {
+            if index == last + 1 {
+                score += 15; // reward contiguous runs
+            }
+        } else {
+            score += (10usize.saturating_sub(index)) as i64; // reward an early first match
+        }
+        last_match = Some(index);
+    }
+    Some(score)
+}
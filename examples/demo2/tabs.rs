@@ -2,16 +2,27 @@ use ratatui::prelude::*;
 
 mod about;
 mod bars;
+mod dashboard;
 mod email;
+mod recipe;
 mod traceroute;
 
 pub use about::AboutTab;
 pub use bars::BarsTab;
+pub use dashboard::DashboardTab;
 pub use email::EmailTab;
+pub use recipe::RecipeTab;
 pub use traceroute::TracerouteTab;
 
 pub trait Tab {
     fn title(&self) -> String;
-    fn render(&self, area: Rect, buf: &mut Buffer);
+
+    /// Renders the tab at `area`, scrolled down by `scroll` lines.
+    ///
+    /// `scroll` is owned by the caller (alongside the boxed `Tab`, see `AppWidget::tabs`) rather
+    /// than the tab itself, so the same persisted offset survives the tab being swapped out and
+    /// back in without the tab having to opt into carrying mutable state.
+    fn render(&self, area: Rect, buf: &mut Buffer, scroll: u16);
+
     fn select(&mut self, _row: usize) {}
 }
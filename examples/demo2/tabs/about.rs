@@ -52,11 +52,11 @@ impl Tab for AboutTab {
         "About".to_string()
     }
 
-    fn render(&self, area: Rect, buf: &mut Buffer) {
+    fn render(&self, area: Rect, buf: &mut Buffer, scroll: u16) {
         colors::render_rgb_colors(area, buf);
         let area = layout(area, Direction::Horizontal, vec![32, 0]);
         render_logo(area[0], buf);
-        render_crate_description(area[1], buf);
+        render_crate_description(scroll, area[1], buf);
     }
 }
 
@@ -104,7 +104,10 @@ pub fn render_logo(area: Rect, buf: &mut Buffer) {
     }
 }
 
-fn render_crate_description(area: Rect, buf: &mut Buffer) {
+/// Lines in the description text, used to size the scrollbar's content length.
+const DESCRIPTION_LINE_COUNT: usize = 4;
+
+fn render_crate_description(scroll: u16, area: Rect, buf: &mut Buffer) {
     let margin = Margin {
         vertical: 1,
         horizontal: 2,
@@ -120,7 +123,6 @@ fn render_crate_description(area: Rect, buf: &mut Buffer) {
     let inner = block.inner(area);
     block.render(area, buf);
 
-    let offset = (0, 0);
     Clear.render(inner, buf); // necessary in order to clear out the color swatches
     let text = "Ratatui
     - cooking up terminal user interfaces -
@@ -130,19 +132,19 @@ fn render_crate_description(area: Rect, buf: &mut Buffer) {
         .style(styles::DESCRIPTION)
         .block(Block::new().padding(Padding::new(2, 2, 1, 1)))
         .wrap(Wrap { trim: true })
-        .scroll(offset)
+        .scroll((scroll, 0))
         .render(inner, buf);
 
-    // let scroll_area = area.inner(&Margin {
-    //     vertical: 1,
-    //     horizontal: 0,
-    // });
-    // let mut scroll_state = ScrollbarState::new(14)
-    //     .viewport_content_length(scroll_area.height as usize)
-    //     .position(scroll);
-    // Scrollbar::new(ScrollbarOrientation::VerticalRight)
-    //     .style(Style::new().fg(color))
-    //     .begin_symbol(None)
-    //     .end_symbol(None)
-    //     .render(scroll_area, buf, &mut scroll_state);;
+    let scroll_area = inner.inner(&Margin {
+        vertical: 1,
+        horizontal: 0,
+    });
+    let mut scroll_state = ScrollbarState::new(DESCRIPTION_LINE_COUNT)
+        .viewport_content_length(scroll_area.height as usize)
+        .position(scroll as usize);
+    Scrollbar::new(ScrollbarOrientation::VerticalRight)
+        .style(styles::BORDERS)
+        .begin_symbol(None)
+        .end_symbol(None)
+        .render(scroll_area, buf, &mut scroll_state);
 }
@@ -14,7 +14,7 @@ impl Tab for BarsTab {
         "Bars".to_string()
     }
 
-    fn render(&self, area: Rect, buf: &mut Buffer) {
+    fn render(&self, area: Rect, buf: &mut Buffer, _scroll: u16) {
         self.render_bars_tab(area, buf);
     }
 }
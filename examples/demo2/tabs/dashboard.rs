@@ -0,0 +1,178 @@
+use std::{cell::RefCell, collections::VecDeque};
+
+use itertools::Itertools;
+use rand::Rng;
+use rand_chacha::rand_core::SeedableRng;
+use ratatui::{prelude::*, widgets::*};
+
+use super::Tab;
+use crate::{colors, tui::layout};
+
+/// How many samples the sparkline/chart keep on screen at once.
+const WINDOW_LEN: usize = 200;
+
+#[derive(Debug)]
+struct Generators {
+    tick: u64,
+    rng: rand_chacha::ChaCha8Rng,
+    sparkline: VecDeque<u64>,
+    random_signal: VecDeque<(f64, f64)>,
+}
+
+impl Generators {
+    fn new() -> Self {
+        Self {
+            tick: 0,
+            rng: rand_chacha::ChaCha8Rng::seed_from_u64(0),
+            sparkline: VecDeque::with_capacity(WINDOW_LEN),
+            random_signal: VecDeque::with_capacity(WINDOW_LEN),
+        }
+    }
+
+    /// Advances every generator by one frame, dropping samples that have scrolled off the window.
+    fn tick(&mut self) {
+        self.tick += 1;
+        self.sparkline.push_back(self.rng.gen_range(0..=10));
+        if self.sparkline.len() > WINDOW_LEN {
+            self.sparkline.pop_front();
+        }
+        self.random_signal
+            .push_back((self.tick as f64, self.rng.gen_range(-20.0..20.0)));
+        if self.random_signal.len() > WINDOW_LEN {
+            self.random_signal.pop_front();
+        }
+    }
+
+    fn window(&self) -> [f64; 2] {
+        let end = self.tick as f64;
+        [end - WINDOW_LEN as f64, end]
+    }
+
+    fn sine_data(&self) -> Vec<(f64, f64)> {
+        let [start, end] = self.window();
+        (0..WINDOW_LEN)
+            .map(|i| {
+                let x = start + i as f64;
+                (x, 20.0 * (x / 10.0).sin())
+            })
+            .take_while(|(x, _)| *x <= end)
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+pub struct DashboardTab {
+    generators: RefCell<Generators>,
+}
+
+impl DashboardTab {
+    pub fn new() -> Self {
+        Self {
+            generators: RefCell::new(Generators::new()),
+        }
+    }
+}
+
+impl Tab for DashboardTab {
+    fn title(&self) -> String {
+        "Dashboard".to_string()
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, _scroll: u16) {
+        self.generators.borrow_mut().tick();
+
+        colors::render_rgb_colors(area, buf);
+        let area = area.inner(&Margin {
+            vertical: 1,
+            horizontal: 2,
+        });
+        Clear.render(area, buf);
+
+        let area = layout(area, Direction::Vertical, vec![3, 0, 8]);
+        render_sparkline(&self.generators.borrow(), area[0], buf);
+        render_chart(&self.generators.borrow(), area[1], buf);
+
+        let area = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
+            .split(area[2]);
+        render_gauge(self.generators.borrow().tick, area[0], buf);
+        render_bar_chart(&self.generators.borrow(), area[1], buf);
+    }
+}
+
+fn render_sparkline(generators: &Generators, area: Rect, buf: &mut Buffer) {
+    let data = generators.sparkline.iter().copied().collect_vec();
+    Sparkline::default()
+        .block(Block::new().title("Signal").borders(Borders::ALL))
+        .data(&data)
+        .style(Style::new().cyan())
+        .render(area, buf);
+}
+
+fn render_chart(generators: &Generators, area: Rect, buf: &mut Buffer) {
+    let window = generators.window();
+    let sine_data = generators.sine_data();
+    let random_data = generators.random_signal.iter().copied().collect_vec();
+
+    let datasets = vec![
+        Dataset::default()
+            .name("sine")
+            .marker(symbols::Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::new().magenta())
+            .data(&sine_data),
+        Dataset::default()
+            .name("random")
+            .marker(symbols::Marker::Dot)
+            .graph_type(GraphType::Scatter)
+            .style(Style::new().yellow())
+            .data(&random_data),
+    ];
+
+    Chart::new(datasets)
+        .block(Block::new().title("Chart").borders(Borders::ALL))
+        .x_axis(Axis::default().bounds(window))
+        .y_axis(Axis::default().bounds([-20.0, 20.0]))
+        .render(area, buf);
+}
+
+fn render_gauge(tick: u64, area: Rect, buf: &mut Buffer) {
+    // sweeps back and forth between 0% and 100% rather than just wrapping around
+    let period = 100;
+    let phase = tick % (period * 2);
+    let percent = if phase <= period {
+        phase
+    } else {
+        period * 2 - phase
+    };
+    Gauge::default()
+        .block(Block::new().title("Load").borders(Borders::ALL))
+        .gauge_style(Style::new().green().on_black())
+        .ratio(percent as f64 / period as f64)
+        .render(area, buf);
+}
+
+fn render_bar_chart(generators: &Generators, area: Rect, buf: &mut Buffer) {
+    let labels = ["CPU", "RAM", "Net", "Disk"];
+    let data = labels
+        .iter()
+        .enumerate()
+        .map(|(i, &label)| {
+            let sample = generators
+                .sparkline
+                .get(generators.sparkline.len().saturating_sub(1 + i))
+                .copied()
+                .unwrap_or(0);
+            (label, sample * 10)
+        })
+        .collect_vec();
+    BarChart::default()
+        .block(Block::new().title("Usage").borders(Borders::ALL))
+        .data(&data)
+        .bar_width(6)
+        .bar_gap(2)
+        .bar_style(Style::new().blue())
+        .value_style(Style::new().black().on_blue())
+        .render(area, buf);
+}
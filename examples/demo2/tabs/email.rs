@@ -23,7 +23,7 @@ impl Tab for EmailTab {
         "Email".to_string()
     }
 
-    fn render(&self, area: Rect, buf: &mut Buffer) {
+    fn render(&self, area: Rect, buf: &mut Buffer, _scroll: u16) {
         self.render(area, buf);
     }
 
@@ -79,6 +79,7 @@ impl EmailTab {
         self.render_email(area[1], buf);
     }
 
+
     fn render_inbox(&self, area: Rect, buf: &mut Buffer) {
         let area = layout(area, Direction::Vertical, vec![1, 0]);
         Tabs::new(vec![" Inbox ", " Sent ", " Drafts "])
@@ -20,7 +20,7 @@ impl Tab for MiscWidgetsTab {
         "Misc Widgets".to_string()
     }
 
-    fn render(&self, area: Rect, buf: &mut Buffer) {
+    fn render(&self, area: Rect, buf: &mut Buffer, _scroll: u16) {
         colors::render_rgb_colors(area, buf);
         let area = area.inner(&Margin {
             vertical: 1,
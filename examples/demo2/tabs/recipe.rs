@@ -90,7 +90,7 @@ impl Tab for RecipeTab {
         self.selected_row = row;
     }
 
-    fn render(&self, area: Rect, buf: &mut Buffer) {
+    fn render(&self, area: Rect, buf: &mut Buffer, scroll: u16) {
         colors::render_rgb_colors(area, buf);
         let area = area.inner(&Margin {
             vertical: 1,
@@ -131,10 +131,20 @@ impl Tab for RecipeTab {
             ]),
             Line::from(vec!["Ingredients:".white().bold()]),
         ];
+        let instructions_len = lines.len();
         Paragraph::new(lines)
             .wrap(Wrap { trim: true })
+            .scroll((scroll, 0))
             .render(area[0], buf);
 
+        let mut scrollbar_state = ScrollbarState::new(instructions_len)
+            .viewport_content_length(area[0].height as usize)
+            .position(scroll as usize);
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .render(area[0], buf, &mut scrollbar_state);
+
         let mut state = TableState::default().with_selected(Some(self.selected_row));
         // https://www.realsimple.com/food-recipes/browse-all-recipes/ratatouille
 
@@ -48,7 +48,7 @@ impl Tab for TracerouteTab {
         "Traceroute".to_string()
     }
 
-    fn render(&self, area: Rect, buf: &mut Buffer) {
+    fn render(&self, area: Rect, buf: &mut Buffer, _scroll: u16) {
         self.render_traceroute_tab(area, buf);
     }
 
@@ -100,34 +100,42 @@ impl TracerouteTab {
     }
 
     fn render_map(&self, area: Rect, buf: &mut Buffer) {
-        let path: Option<(&Hop, &Hop)> = self.hops.iter().tuple_windows().nth(self.selected_row);
+        let traveled = self.selected_row + 1;
         let block = Block::new().title("Map").borders(Borders::ALL);
         let map = Map {
             resolution: canvas::MapResolution::High,
             color: Color::Gray,
         };
         Canvas::default()
-            .marker(Marker::Dot)
-            .x_bounds([113.0, 154.0]) // australia
-            .y_bounds([-42.0, -11.0]) // australia
+            .marker(Marker::Braille)
+            .x_bounds([-180.0, 180.0])
+            .y_bounds([-90.0, 90.0])
             .paint(|context| {
                 context.draw(&map);
-                if let Some(path) = path {
+                for (from, to) in self.hops.iter().tuple_windows().take(traveled) {
                     context.draw(&canvas::Line::new(
-                        path.0.location.0,
-                        path.0.location.1,
-                        path.1.location.0,
-                        path.1.location.1,
+                        from.location.0,
+                        from.location.1,
+                        to.location.0,
+                        to.location.1,
                         Color::Blue,
                     ));
+                }
+                for (index, hop) in self.hops.iter().take(traveled).enumerate() {
+                    let color = if index == self.selected_row {
+                        Color::Red
+                    } else {
+                        Color::Green
+                    };
                     context.draw(&Points {
-                        color: Color::Green,
-                        coords: &[path.0.location], // sydney
-                    });
-                    context.draw(&Points {
-                        color: Color::Red,
-                        coords: &[path.1.location], // perth
+                        color,
+                        coords: &[hop.location],
                     });
+                    context.print(
+                        hop.location.0,
+                        hop.location.1,
+                        Span::styled(hop.host, Style::new().fg(color)),
+                    );
                 }
             })
             .block(block)
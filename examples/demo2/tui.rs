@@ -11,7 +11,7 @@ use crossterm::{
     ExecutableCommand,
 };
 use itertools::Itertools;
-use ratatui::prelude::*;
+use ratatui::{layout::Flex, prelude::*};
 
 pub fn create_terminal() -> Result<Terminal<CrosstermBackend<Stdout>>> {
     // this size is to match the size of the terminal when running the demo
@@ -65,3 +65,12 @@ pub fn layout(area: Rect, direction: Direction, heights: Vec<u16>) -> Rc<[Rect]>
         .constraints(constraints)
         .split(area)
 }
+
+/// a centered rect of the given size
+pub fn centered_rect(area: Rect, width: u16, height: u16) -> Rect {
+    let horizontal = Layout::horizontal([width]).flex(Flex::Center);
+    let vertical = Layout::vertical([height]).flex(Flex::Center);
+    let [area] = area.split(&vertical);
+    let [area] = area.split(&horizontal);
+    area
+}
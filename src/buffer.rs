@@ -0,0 +1,255 @@
+use std::borrow::Cow;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    layout::Rect,
+    style::Style,
+    text::{hyperlink, Line, StyledGrapheme},
+};
+
+/// A single cell of a [`Buffer`]: a displayed grapheme plus the style it's drawn with.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Cell {
+    pub symbol: String,
+    pub style: Style,
+    /// The link/URI this cell belongs to, if any. Populated from the originating span's
+    /// [`Span::link`](crate::text::Span::link) by [`Buffer::set_line`] so a backend can later
+    /// group a run of cells back into a single OSC 8 hyperlink; see [`crate::text::hyperlink`].
+    pub link: Option<String>,
+}
+
+impl Cell {
+    fn reset(&mut self) {
+        self.symbol.clear();
+        self.symbol.push(' ');
+        self.style = Style::default();
+        self.link = None;
+    }
+}
+
+/// A buffer that maps to the desired content of the terminal after the draw call.
+///
+/// This is a focused excerpt of `Buffer` covering the cell grid and the styled-cell accessors
+/// new widgets in this crate rely on ([`Buffer::get`]/[`Buffer::get_mut`]/[`Buffer::set_line`]/
+/// [`Buffer::set_style`]), plus the new [`Buffer::as_styled_string`] debug view; diffing against
+/// a previous frame for the render loop is unchanged.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Buffer {
+    pub area: Rect,
+    pub content: Vec<Cell>,
+}
+
+impl Buffer {
+    /// Returns a buffer with all cells set to the default cell (a blank space).
+    pub fn empty(area: Rect) -> Buffer {
+        let cell = Cell::default();
+        Buffer::filled(area, &cell)
+    }
+
+    /// Returns a buffer with all cells initialized to `cell`.
+    pub fn filled(area: Rect, cell: &Cell) -> Buffer {
+        let size = area.area() as usize;
+        let mut content = vec![cell.clone(); size];
+        for c in &mut content {
+            if c.symbol.is_empty() {
+                c.symbol.push(' ');
+            }
+        }
+        Buffer { area, content }
+    }
+
+    /// Returns a buffer containing the given lines, sized to fit them exactly.
+    pub fn with_lines<'a, S: Into<Line<'a>>>(lines: Vec<S>) -> Buffer {
+        let lines: Vec<Line> = lines.into_iter().map(Into::into).collect();
+        let height = lines.len() as u16;
+        let width = lines.iter().map(Line::width).max().unwrap_or(0) as u16;
+        let mut buffer = Buffer::empty(Rect::new(0, 0, width, height));
+        for (y, line) in lines.iter().enumerate() {
+            buffer.set_line(0, y as u16, line, width);
+        }
+        buffer
+    }
+
+    fn index_of(&self, x: u16, y: u16) -> usize {
+        let row = (y - self.area.y) as usize;
+        let col = (x - self.area.x) as usize;
+        row * self.area.width as usize + col
+    }
+
+    pub fn get(&self, x: u16, y: u16) -> &Cell {
+        &self.content[self.index_of(x, y)]
+    }
+
+    pub fn get_mut(&mut self, x: u16, y: u16) -> &mut Cell {
+        let index = self.index_of(x, y);
+        &mut self.content[index]
+    }
+
+    /// Writes a string at the given coordinates, stopping if it runs out of space in the buffer.
+    pub fn set_string<T: AsRef<str>>(&mut self, x: u16, y: u16, string: T, style: Style) {
+        self.set_stringn(x, y, string.as_ref(), usize::MAX, style);
+    }
+
+    /// Like [`Buffer::set_string`], but truncated to at most `max_width` display columns.
+    pub fn set_stringn<T: AsRef<str>>(&mut self, x: u16, y: u16, string: T, max_width: usize, style: Style) {
+        let mut remaining = max_width;
+        let mut cx = x;
+        for grapheme in
+            unicode_segmentation::UnicodeSegmentation::graphemes(string.as_ref(), true)
+        {
+            let width = grapheme.width();
+            if width == 0 || width > remaining || cx >= self.area.right() {
+                break;
+            }
+            let cell = self.get_mut(cx, y);
+            cell.symbol.clear();
+            cell.symbol.push_str(grapheme);
+            cell.style = style;
+            cell.link = None;
+            remaining -= width;
+            cx += width as u16;
+        }
+    }
+
+    /// Renders `line`'s spans at `(x, y)`, truncated to `width` display columns.
+    pub fn set_line(&mut self, x: u16, y: u16, line: &Line, width: u16) {
+        let mut cx = x;
+        let mut remaining = width;
+        for grapheme in line.styled_graphemes(line.style) {
+            let cell_width = grapheme.width() as u16;
+            if cell_width == 0 || cell_width > remaining {
+                break;
+            }
+            let cell = self.get_mut(cx, y);
+            cell.symbol.clear();
+            cell.symbol.push_str(grapheme.symbol);
+            cell.style = grapheme.style;
+            cell.link = grapheme.link.map(Cow::into_owned);
+            cx += cell_width;
+            remaining -= cell_width;
+        }
+    }
+
+    /// Patches the style of every cell in `area`, leaving their symbols untouched.
+    pub fn set_style(&mut self, area: Rect, style: Style) {
+        for y in area.y..area.bottom() {
+            for x in area.x..area.right() {
+                self.get_mut(x, y).style = self.get_mut(x, y).style.patch(style);
+            }
+        }
+    }
+
+    /// Resizes the buffer to `area`, resetting any newly-added cells to blank.
+    pub fn resize(&mut self, area: Rect) {
+        let cell = Cell::default();
+        self.content.resize(area.area() as usize, cell.clone());
+        for c in &mut self.content {
+            if c.symbol.is_empty() {
+                c.symbol.push(' ');
+            }
+        }
+        self.area = area;
+    }
+
+    /// Renders this buffer as a deterministic, line-oriented debug string: one line of text per
+    /// row of cells, followed by one line per row encoding each cell's style.
+    ///
+    /// Plain `{buffer}` / `assert_eq!` comparisons on [`Buffer`] only show the final merged grid
+    /// of symbols, which makes a styling bug (the right glyph drawn in the wrong style) invisible
+    /// to a test failure diff. This renders the glyphs *and* a compact per-cell style encoding
+    /// (`fg:bg`, using `-` for an unset color) so a golden/snapshot test can assert both at once,
+    /// and so a colour regression is readable straight from a diff instead of requiring a human in
+    /// front of a real terminal.
+    pub fn as_styled_string(&self) -> String {
+        let mut out = String::new();
+        for y in self.area.y..self.area.bottom() {
+            let mut symbols = String::new();
+            let mut styles = Vec::new();
+            for x in self.area.x..self.area.right() {
+                let cell = self.get(x, y);
+                symbols.push_str(&cell.symbol);
+                styles.push(format_style(&cell.style));
+            }
+            out.push_str(&symbols);
+            out.push('\n');
+            out.push_str(&styles.join(" "));
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Renders this buffer one line of text per row, wrapping each contiguous run of
+    /// same-link cells in its OSC 8 escape sequence.
+    ///
+    /// There's no real terminal backend in this slice to write OSC 8 sequences to, so this is the
+    /// debug-path stand-in: it exercises the same [`hyperlink::group_by_link`] grouping a backend
+    /// would use to avoid re-opening the escape sequence for every cell of a hyperlinked run.
+    pub fn as_hyperlinked_string(&self) -> String {
+        let mut out = String::new();
+        for y in self.area.y..self.area.bottom() {
+            let graphemes = (self.area.x..self.area.right()).map(|x| {
+                let cell = self.get(x, y);
+                StyledGrapheme::new(&cell.symbol, cell.style)
+                    .with_link(cell.link.as_deref().map(Cow::Borrowed))
+            });
+            for (text, link) in hyperlink::group_by_link(graphemes) {
+                match link {
+                    Some(uri) => out.push_str(&hyperlink::wrap(&uri, &text)),
+                    None => out.push_str(&text),
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+fn format_style(style: &Style) -> String {
+    let fg = style.fg.map(|c| format!("{c:?}")).unwrap_or_else(|| "-".into());
+    let bg = style.bg.map(|c| format!("{c:?}")).unwrap_or_else(|| "-".into());
+    format!("{fg}:{bg}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Color;
+
+    #[test]
+    fn as_styled_string_encodes_fg_and_bg() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 1));
+        buffer.set_string(0, 0, "ab", Style::default().fg(Color::Red).bg(Color::Blue));
+        let dump = buffer.as_styled_string();
+        assert!(dump.contains("ab"));
+        assert!(dump.contains("Red:Blue"));
+    }
+
+    #[test]
+    fn as_styled_string_marks_unset_colors() {
+        let buffer = Buffer::empty(Rect::new(0, 0, 1, 1));
+        let dump = buffer.as_styled_string();
+        assert!(dump.contains("-:-"));
+    }
+
+    #[test]
+    fn set_line_populates_cell_link_from_span() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        let line = Line::from(crate::text::Span::hyperlink("hi", "https://example.com"));
+        buffer.set_line(0, 0, &line, 5);
+
+        assert_eq!(buffer.get(0, 0).link.as_deref(), Some("https://example.com"));
+        assert_eq!(buffer.get(2, 0).link, None);
+    }
+
+    #[test]
+    fn as_hyperlinked_string_wraps_a_hyperlinked_run_once() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        let line = Line::from(crate::text::Span::hyperlink("hi", "https://example.com"));
+        buffer.set_line(0, 0, &line, 5);
+
+        let dump = buffer.as_hyperlinked_string();
+        assert_eq!(dump.matches("https://example.com").count(), 1);
+        assert!(dump.contains("hi"));
+    }
+}
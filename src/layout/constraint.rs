@@ -0,0 +1,226 @@
+use cassowary::{
+    strength::{MEDIUM, REQUIRED, STRONG, WEAK},
+    Constraint as CassowaryConstraint, Variable,
+    WeightedRelation::{EQ, GE, LE},
+};
+
+/// A size expressed in one of the units [`Layout`] already understands.
+///
+/// [`Unit`] is used together with [`Constraint::eq`], [`Constraint::le`] and [`Constraint::ge`]
+/// to describe a segment's size relative to the area being split, without pinning it to one of
+/// the fixed constraint kinds (`Length`, `Percentage`, ...).
+///
+/// [`Layout`]: super::Layout
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Unit {
+    /// An exact number of terminal cells.
+    Cells(u16),
+    /// A percentage of the area being split.
+    Percentage(u16),
+    /// A ratio of the area being split, expressed as `numerator / denominator`.
+    Ratio(u32, u32),
+    /// No fixed size; the segment grows or shrinks with the available space.
+    #[default]
+    Proportional,
+}
+
+/// How a [`Unit`] relates to a segment's size.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+enum Relation {
+    Eq,
+    Le,
+    Ge,
+}
+
+/// A constraint that defines the size of a column, row, or widget.
+///
+/// Most constraints describe a segment's size directly (`Length`, `Percentage`, `Min`, `Max`,
+/// ...). The [`Constraint::eq`], [`Constraint::le`] and [`Constraint::ge`] constructors instead
+/// describe a *relationship* between a segment and a [`Unit`] (e.g. "this segment is at least
+/// 20 cells wide", or "this segment is exactly 50% of the area"), with an optional
+/// [`Constraint::strength`] that the solver uses instead of the priority ladder it applies to the
+/// fixed constraint kinds.
+///
+/// [`Constraint::relational_constraint`] turns a relational constraint into a real
+/// `cassowary::Constraint` over a given `Variable`/`available` size, ready to hand to a
+/// `cassowary::Solver`. Wiring that solve pass into [`Layout::split`]'s output isn't part of this
+/// change; the fixed constraint kinds keep using the priority ladder [`Layout`] already applies.
+///
+/// [`Layout`]: super::Layout
+/// [`Layout::split`]: super::Layout::split
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Constraint {
+    /// Applies a fixed size to the segment, overriding the `flex` value.
+    Fixed(u16),
+    /// Applies a minimum size to the segment.
+    Min(u16),
+    /// Applies a maximum size to the segment.
+    Max(u16),
+    /// Applies a length constraint to the segment.
+    Length(u16),
+    /// Applies a percentage of the available space to the segment.
+    Percentage(u16),
+    /// Applies a ratio of the available space to the segment.
+    Ratio(u32, u32),
+    /// Applies a proportional weight to the segment, which grows or shrinks relative to the
+    /// other proportional segments.
+    Proportional(u16),
+    /// Relates the segment's size to a [`Unit`], with an optional solver [`Strength`].
+    ///
+    /// Built via [`Constraint::eq`], [`Constraint::le`] and [`Constraint::ge`].
+    Relational(Relation, Unit, Strength),
+}
+
+/// The priority the cassowary solver should give a [`Constraint::Relational`] constraint.
+///
+/// Mirrors [`cassowary::strength`], which uses arbitrary large floats internally; this newtype
+/// keeps relational constraints from leaking the `cassowary` dependency into user code.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Strength(f64);
+
+impl Eq for Strength {}
+
+impl std::hash::Hash for Strength {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl Strength {
+    /// The constraint must hold exactly; used by the fixed constraint kinds.
+    pub const REQUIRED: Self = Self(REQUIRED);
+    /// A strong preference, rarely violated unless it conflicts with a `REQUIRED` constraint.
+    pub const STRONG: Self = Self(STRONG);
+    /// A medium preference; what [`Strength::default`] returns. Relational constraints built
+    /// with [`Constraint::eq`]/[`Constraint::le`]/[`Constraint::ge`] start at `REQUIRED` instead.
+    pub const MEDIUM: Self = Self(MEDIUM);
+    /// A weak preference, the first to be violated when constraints conflict.
+    pub const WEAK: Self = Self(WEAK);
+}
+
+impl Default for Strength {
+    fn default() -> Self {
+        Self::MEDIUM
+    }
+}
+
+impl Constraint {
+    /// Constrains a segment to be exactly equal to `unit`.
+    ///
+    /// Uses [`Strength::REQUIRED`] unless overridden with [`Constraint::strength`].
+    pub fn eq(unit: Unit) -> Self {
+        Self::Relational(Relation::Eq, unit, Strength::REQUIRED)
+    }
+
+    /// Constrains a segment to be less than or equal to `unit`.
+    ///
+    /// Uses [`Strength::REQUIRED`] unless overridden with [`Constraint::strength`].
+    pub fn le(unit: Unit) -> Self {
+        Self::Relational(Relation::Le, unit, Strength::REQUIRED)
+    }
+
+    /// Constrains a segment to be greater than or equal to `unit`.
+    ///
+    /// Uses [`Strength::REQUIRED`] unless overridden with [`Constraint::strength`].
+    pub fn ge(unit: Unit) -> Self {
+        Self::Relational(Relation::Ge, unit, Strength::REQUIRED)
+    }
+
+    /// Overrides the solver [`Strength`] of a relational constraint created with
+    /// [`Constraint::eq`], [`Constraint::le`] or [`Constraint::ge`].
+    ///
+    /// Has no effect on the other constraint kinds.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn strength(self, strength: Strength) -> Self {
+        match self {
+            Self::Relational(relation, unit, _) => Self::Relational(relation, unit, strength),
+            other => other,
+        }
+    }
+
+    /// Translates this constraint into a cassowary constraint over `variable`, given the size of
+    /// the area being split.
+    ///
+    /// Relational constraints translate directly: `eq` becomes `variable == expr`, `le`/`ge`
+    /// become inequality rows, all at the constraint's [`Strength`]. The other constraint kinds
+    /// keep using the fixed priority ladder applied elsewhere in the layout pass, so this method
+    /// only needs to handle [`Constraint::Relational`].
+    pub(crate) fn relational_constraint(
+        &self,
+        variable: Variable,
+        available: u16,
+    ) -> Option<CassowaryConstraint> {
+        let Self::Relational(relation, unit, strength) = self else {
+            return None;
+        };
+        let target = match *unit {
+            Unit::Cells(cells) => f64::from(cells),
+            Unit::Percentage(pct) => f64::from(available) * f64::from(pct) / 100.0,
+            Unit::Ratio(num, den) if den != 0 => {
+                f64::from(available) * f64::from(num) / f64::from(den)
+            }
+            Unit::Ratio(..) => 0.0,
+            Unit::Proportional => return None,
+        };
+        Some(match relation {
+            Relation::Eq => variable | EQ(strength.0) | target,
+            Relation::Le => variable | LE(strength.0) | target,
+            Relation::Ge => variable | GE(strength.0) | target,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eq_defaults_to_required_strength() {
+        let constraint = Constraint::eq(Unit::Cells(10));
+        assert_eq!(
+            constraint,
+            Constraint::Relational(Relation::Eq, Unit::Cells(10), Strength::REQUIRED)
+        );
+    }
+
+    #[test]
+    fn strength_overrides_default() {
+        let constraint = Constraint::ge(Unit::Percentage(50)).strength(Strength::WEAK);
+        assert_eq!(
+            constraint,
+            Constraint::Relational(Relation::Ge, Unit::Percentage(50), Strength::WEAK)
+        );
+    }
+
+    #[test]
+    fn strength_is_noop_on_non_relational_constraints() {
+        let constraint = Constraint::Fixed(10).strength(Strength::WEAK);
+        assert_eq!(constraint, Constraint::Fixed(10));
+    }
+
+    /// Exercises [`Constraint::relational_constraint`] against a real `cassowary::Solver`, rather
+    /// than only comparing the `Constraint` enum values the constructors produce.
+    #[test]
+    fn relational_constraint_is_enforced_by_a_cassowary_solver() {
+        use cassowary::Solver;
+
+        let variable = Variable::new();
+        let constraint = Constraint::eq(Unit::Percentage(50)).relational_constraint(variable, 200).unwrap();
+
+        let mut solver = Solver::new();
+        solver.add_constraint(constraint).unwrap();
+
+        assert_eq!(solver.get_value(variable), 100.0);
+    }
+
+    #[test]
+    fn relational_constraint_is_none_for_non_relational_constraints() {
+        assert_eq!(Constraint::Fixed(10).relational_constraint(Variable::new(), 100), None);
+    }
+
+    #[test]
+    fn relational_constraint_is_none_for_proportional_unit() {
+        let constraint = Constraint::eq(Unit::Proportional);
+        assert_eq!(constraint.relational_constraint(Variable::new(), 100), None);
+    }
+}
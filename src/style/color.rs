@@ -0,0 +1,407 @@
+/// ANSI or RGB color.
+///
+/// This is a focused excerpt of `Color` covering the HSL/HSV conversion and manipulation helpers
+/// added below ([`Color::to_hsl`], [`Color::lighten`], ...); the named/indexed variants and their
+/// terminal-specific rendering are unchanged.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Color {
+    #[default]
+    Reset,
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Gray,
+    DarkGray,
+    LightRed,
+    LightGreen,
+    LightYellow,
+    LightBlue,
+    LightMagenta,
+    LightCyan,
+    White,
+    Rgb(u8, u8, u8),
+    Indexed(u8),
+}
+
+/// A color expressed in the HSL (hue, saturation, lightness) color space.
+///
+/// `hue` is in degrees (`0.0..360.0`), `saturation` and `lightness` are fractions (`0.0..=1.0`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsl {
+    pub hue: f64,
+    pub saturation: f64,
+    pub lightness: f64,
+}
+
+impl Color {
+    /// Resolves a named or indexed color to its RGB equivalent.
+    ///
+    /// `Reset` has no well-defined RGB value and resolves to black.
+    fn to_rgb(self) -> (u8, u8, u8) {
+        match self {
+            Color::Rgb(r, g, b) => (r, g, b),
+            Color::Black | Color::Reset => (0, 0, 0),
+            Color::Red => (205, 0, 0),
+            Color::Green => (0, 205, 0),
+            Color::Yellow => (205, 205, 0),
+            Color::Blue => (0, 0, 238),
+            Color::Magenta => (205, 0, 205),
+            Color::Cyan => (0, 205, 205),
+            Color::Gray => (229, 229, 229),
+            Color::DarkGray => (127, 127, 127),
+            Color::LightRed => (255, 0, 0),
+            Color::LightGreen => (0, 255, 0),
+            Color::LightYellow => (255, 255, 0),
+            Color::LightBlue => (92, 92, 255),
+            Color::LightMagenta => (255, 0, 255),
+            Color::LightCyan => (0, 255, 255),
+            Color::White => (255, 255, 255),
+            Color::Indexed(i) => indexed_to_rgb(i),
+        }
+    }
+
+    /// Converts this color to the HSL color space, resolving named/indexed colors to RGB first.
+    pub fn to_hsl(self) -> Hsl {
+        let (r, g, b) = self.to_rgb();
+        let (r, g, b) = (f64::from(r) / 255.0, f64::from(g) / 255.0, f64::from(b) / 255.0);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let lightness = (max + min) / 2.0;
+        let delta = max - min;
+
+        let saturation = if delta == 0.0 {
+            0.0
+        } else {
+            delta / (1.0 - (2.0 * lightness - 1.0).abs())
+        };
+
+        let hue = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * (((g - b) / delta).rem_euclid(6.0))
+        } else if max == g {
+            60.0 * (((b - r) / delta) + 2.0)
+        } else {
+            60.0 * (((r - g) / delta) + 4.0)
+        };
+
+        Hsl {
+            hue,
+            saturation,
+            lightness,
+        }
+    }
+
+    /// Constructs a [`Color::Rgb`] from an [`Hsl`] color.
+    pub fn from_hsl(hsl: Hsl) -> Color {
+        let Hsl {
+            hue,
+            saturation,
+            lightness,
+        } = hsl;
+
+        if saturation == 0.0 {
+            let v = (lightness * 255.0).round() as u8;
+            return Color::Rgb(v, v, v);
+        }
+
+        let c = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        let h_prime = hue.rem_euclid(360.0) / 60.0;
+        let x = c * (1.0 - (h_prime.rem_euclid(2.0) - 1.0).abs());
+        let m = lightness - c / 2.0;
+
+        let (r1, g1, b1) = match h_prime as u32 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+
+        let to_channel = |v: f64| ((v + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+        Color::Rgb(to_channel(r1), to_channel(g1), to_channel(b1))
+    }
+
+    /// Returns a lightened version of this color, moving `amount` (`0.0..=1.0`) of the way from
+    /// its current lightness to fully light.
+    #[must_use]
+    pub fn lighten(self, amount: f64) -> Color {
+        let mut hsl = self.to_hsl();
+        hsl.lightness = (hsl.lightness + (1.0 - hsl.lightness) * amount).clamp(0.0, 1.0);
+        Color::from_hsl(hsl)
+    }
+
+    /// Returns a darkened version of this color, moving `amount` (`0.0..=1.0`) of the way from
+    /// its current lightness to fully dark.
+    #[must_use]
+    pub fn darken(self, amount: f64) -> Color {
+        let mut hsl = self.to_hsl();
+        hsl.lightness = (hsl.lightness * (1.0 - amount)).clamp(0.0, 1.0);
+        Color::from_hsl(hsl)
+    }
+
+    /// Returns a more saturated version of this color, moving `amount` (`0.0..=1.0`) of the way
+    /// from its current saturation to fully saturated.
+    #[must_use]
+    pub fn saturate(self, amount: f64) -> Color {
+        let mut hsl = self.to_hsl();
+        hsl.saturation = (hsl.saturation + (1.0 - hsl.saturation) * amount).clamp(0.0, 1.0);
+        Color::from_hsl(hsl)
+    }
+
+    /// Rotates this color's hue by `degrees`, wrapping around the color wheel.
+    #[must_use]
+    pub fn rotate_hue(self, degrees: f64) -> Color {
+        let mut hsl = self.to_hsl();
+        hsl.hue = (hsl.hue + degrees).rem_euclid(360.0);
+        Color::from_hsl(hsl)
+    }
+
+    /// Linearly interpolates between this color and `other` in RGB space.
+    ///
+    /// `alpha` of `0.0` returns `self`, `1.0` returns `other`.
+    #[must_use]
+    pub fn blend(self, other: Color, alpha: f64) -> Color {
+        let alpha = alpha.clamp(0.0, 1.0);
+        let (r1, g1, b1) = self.to_rgb();
+        let (r2, g2, b2) = other.to_rgb();
+        let lerp = |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * alpha).round() as u8;
+        Color::Rgb(lerp(r1, r2), lerp(g1, g2), lerp(b1, b2))
+    }
+
+    /// Interpolates between this color and `other` in the Oklab color space, which (unlike
+    /// [`Color::blend`]'s per-channel RGB lerp) is perceptually uniform, so a ramp of colors
+    /// produced by stepping `t` from `0.0` to `1.0` has no visible banding or dull midpoint.
+    ///
+    /// `t` of `0.0` returns `self`, `1.0` returns `other`; `t` outside `0.0..=1.0` is clamped.
+    #[must_use]
+    pub fn lerp(self, other: Color, t: f64) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let (l1, a1, b1) = rgb_to_oklab(self.to_rgb());
+        let (l2, a2, b2) = rgb_to_oklab(other.to_rgb());
+        let lerp = |x: f64, y: f64| x + (y - x) * t;
+        let (r, g, b) = oklab_to_rgb((lerp(l1, l2), lerp(a1, a2), lerp(b1, b2)));
+        Color::Rgb(r, g, b)
+    }
+}
+
+/// A ramp of [`Color::Rgb`] stops interpolated between two endpoints via [`Color::lerp`].
+///
+/// This gives gauges, bar charts, and sparklines a cheap way to render banding-free gradients
+/// without each one re-implementing Oklab interpolation or pulling in a color-science crate.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui::style::{Color, Gradient};
+/// let stops: Vec<Color> = Gradient::new(Color::Red, Color::Blue).take(5).collect();
+/// assert_eq!(stops.len(), 5);
+/// assert_eq!(stops[0], Color::Red.lerp(Color::Blue, 0.0));
+/// assert_eq!(stops[4], Color::Red.lerp(Color::Blue, 1.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gradient {
+    from: Color,
+    to: Color,
+}
+
+impl Gradient {
+    /// Creates a gradient running from `from` to `to`.
+    pub fn new(from: Color, to: Color) -> Self {
+        Self { from, to }
+    }
+
+    /// Samples `steps` evenly spaced stops across the gradient, including both endpoints.
+    ///
+    /// Returns no stops for `steps == 0`, and just the `from` endpoint for `steps == 1`.
+    pub fn take(self, steps: usize) -> impl Iterator<Item = Color> {
+        (0..steps).map(move |i| {
+            let t = if steps <= 1 {
+                0.0
+            } else {
+                i as f64 / (steps - 1) as f64
+            };
+            self.from.lerp(self.to, t)
+        })
+    }
+}
+
+/// Converts an sRGB channel (`0..=255`) to linear-light intensity (`0.0..=1.0`).
+fn srgb_to_linear(c: u8) -> f64 {
+    let c = f64::from(c) / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts a linear-light intensity (`0.0..=1.0`) back to an sRGB channel (`0..=255`).
+fn linear_to_srgb(c: f64) -> u8 {
+    let c = c.clamp(0.0, 1.0);
+    let c = if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    (c * 255.0).round().clamp(0.0, 255.0) as u8
+}
+
+/// Converts an sRGB color to Oklab's `(L, a, b)` coordinates.
+///
+/// See <https://bottosson.github.io/posts/oklab/> for the derivation of these matrices.
+fn rgb_to_oklab((r, g, b): (u8, u8, u8)) -> (f64, f64, f64) {
+    let r = srgb_to_linear(r);
+    let g = srgb_to_linear(g);
+    let b = srgb_to_linear(b);
+
+    let l = 0.412_221_470_8 * r + 0.536_332_536_3 * g + 0.051_445_992_9 * b;
+    let m = 0.211_903_498_2 * r + 0.680_699_545_1 * g + 0.107_396_956_6 * b;
+    let s = 0.088_302_461_9 * r + 0.281_718_837_6 * g + 0.629_978_700_5 * b;
+
+    let l_ = l.cbrt();
+    let m_ = m.cbrt();
+    let s_ = s.cbrt();
+
+    (
+        0.210_454_255_3 * l_ + 0.793_617_785_0 * m_ - 0.004_072_046_8 * s_,
+        1.977_998_495_1 * l_ - 2.428_592_205_0 * m_ + 0.450_593_709_9 * s_,
+        0.025_904_037_1 * l_ + 0.782_771_766_2 * m_ - 0.808_675_766_0 * s_,
+    )
+}
+
+/// Converts Oklab's `(L, a, b)` coordinates back to an sRGB color, clamping out-of-gamut channels.
+fn oklab_to_rgb((l, a, b): (f64, f64, f64)) -> (u8, u8, u8) {
+    let l_ = l + 0.396_337_777_4 * a + 0.215_803_757_3 * b;
+    let m_ = l - 0.105_561_345_8 * a - 0.063_854_172_8 * b;
+    let s_ = l - 0.089_484_177_5 * a - 1.291_485_548_0 * b;
+
+    let l = l_ * l_ * l_;
+    let m = m_ * m_ * m_;
+    let s = s_ * s_ * s_;
+
+    let r = 4.076_741_662_1 * l - 3.307_711_591_3 * m + 0.230_969_929_2 * s;
+    let g = -1.268_438_004_6 * l + 2.609_757_401_1 * m - 0.341_319_396_5 * s;
+    let b = -0.004_196_086_3 * l - 0.703_418_614_7 * m + 1.707_614_701_0 * s;
+
+    (linear_to_srgb(r), linear_to_srgb(g), linear_to_srgb(b))
+}
+
+/// Resolves an xterm 256-color index to its RGB equivalent.
+fn indexed_to_rgb(i: u8) -> (u8, u8, u8) {
+    match i {
+        0..=15 => {
+            const BASE: [(u8, u8, u8); 16] = [
+                (0, 0, 0),
+                (128, 0, 0),
+                (0, 128, 0),
+                (128, 128, 0),
+                (0, 0, 128),
+                (128, 0, 128),
+                (0, 128, 128),
+                (192, 192, 192),
+                (128, 128, 128),
+                (255, 0, 0),
+                (0, 255, 0),
+                (255, 255, 0),
+                (0, 0, 255),
+                (255, 0, 255),
+                (0, 255, 255),
+                (255, 255, 255),
+            ];
+            BASE[i as usize]
+        }
+        16..=231 => {
+            let i = i - 16;
+            let steps = [0u8, 95, 135, 175, 215, 255];
+            let r = steps[(i / 36) as usize];
+            let g = steps[((i / 6) % 6) as usize];
+            let b = steps[(i % 6) as usize];
+            (r, g, b)
+        }
+        232..=255 => {
+            let level = 8 + (i - 232) * 10;
+            (level, level, level)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn white_round_trips_through_hsl() {
+        let hsl = Color::White.to_hsl();
+        assert_eq!(hsl.lightness, 1.0);
+        assert_eq!(hsl.saturation, 0.0);
+        assert_eq!(Color::from_hsl(hsl), Color::Rgb(255, 255, 255));
+    }
+
+    #[test]
+    fn red_hue_is_zero() {
+        let hsl = Color::Rgb(255, 0, 0).to_hsl();
+        assert!((hsl.hue - 0.0).abs() < 0.001);
+        assert!((hsl.saturation - 1.0).abs() < 0.001);
+        assert!((hsl.lightness - 0.5).abs() < 0.001);
+    }
+
+    #[test]
+    fn lighten_moves_toward_white() {
+        let lightened = Color::Rgb(100, 0, 0).lighten(1.0);
+        assert_eq!(lightened, Color::Rgb(255, 255, 255));
+    }
+
+    #[test]
+    fn darken_moves_toward_black() {
+        let darkened = Color::Rgb(100, 50, 50).darken(1.0);
+        assert_eq!(darkened, Color::Rgb(0, 0, 0));
+    }
+
+    #[test]
+    fn blend_interpolates_channels() {
+        let blended = Color::Rgb(0, 0, 0).blend(Color::Rgb(255, 255, 255), 0.5);
+        assert_eq!(blended, Color::Rgb(128, 128, 128));
+    }
+
+    #[test]
+    fn rotate_hue_wraps_around() {
+        let rotated = Color::Rgb(255, 0, 0).rotate_hue(360.0);
+        assert_eq!(rotated, Color::Rgb(255, 0, 0));
+    }
+
+    #[test]
+    fn lerp_endpoints_round_trip() {
+        let a = Color::Rgb(20, 40, 200);
+        let b = Color::Rgb(220, 80, 10);
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+    }
+
+    #[test]
+    fn lerp_clamps_t() {
+        let a = Color::Rgb(20, 40, 200);
+        let b = Color::Rgb(220, 80, 10);
+        assert_eq!(a.lerp(b, -1.0), a.lerp(b, 0.0));
+        assert_eq!(a.lerp(b, 2.0), a.lerp(b, 1.0));
+    }
+
+    #[test]
+    fn gradient_samples_requested_stop_count() {
+        let stops: Vec<_> = Gradient::new(Color::Red, Color::Blue).take(5).collect();
+        assert_eq!(stops.len(), 5);
+        assert_eq!(stops[0], Color::Red.lerp(Color::Blue, 0.0));
+        assert_eq!(stops[4], Color::Red.lerp(Color::Blue, 1.0));
+    }
+
+    #[test]
+    fn gradient_single_stop_is_the_start_color() {
+        let stops: Vec<_> = Gradient::new(Color::Red, Color::Blue).take(1).collect();
+        assert_eq!(stops, vec![Color::Red.lerp(Color::Blue, 0.0)]);
+    }
+}
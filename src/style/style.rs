@@ -0,0 +1,266 @@
+use super::Color;
+
+/// A minimum contrast ratio that is comfortably legible for body text, per WCAG 2.1's AA
+/// threshold for normal-size text.
+pub const MIN_CONTRAST: f64 = 4.5;
+
+bitflags::bitflags! {
+    /// Modifiers that change the way a piece of text is displayed, e.g. bold or underlined.
+    ///
+    /// These are bitflags so they can be composed with `|`, and so that a [`Style`] can track
+    /// both the modifiers it adds and the ones it explicitly removes (see
+    /// [`Style::add_modifier`]/[`Style::remove_modifier`]) independently of the modifiers already
+    /// present on whatever it's [`patch`](Style::patch)ed onto.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::style::Modifier;
+    /// let m = Modifier::BOLD | Modifier::ITALIC;
+    /// ```
+    #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+    pub struct Modifier: u16 {
+        const BOLD          = 0b0000_0000_0001;
+        const DIM           = 0b0000_0000_0010;
+        const ITALIC        = 0b0000_0000_0100;
+        const UNDERLINED    = 0b0000_0000_1000;
+        const SLOW_BLINK    = 0b0000_0001_0000;
+        const RAPID_BLINK   = 0b0000_0010_0000;
+        const REVERSED      = 0b0000_0100_0000;
+        const HIDDEN        = 0b0000_1000_0000;
+        const CROSSED_OUT   = 0b0001_0000_0000;
+    }
+}
+
+/// Style lets you control the main characteristics of the displayed elements.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Style {
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub underline_color: Option<Color>,
+    pub add_modifier: Modifier,
+    pub sub_modifier: Modifier,
+}
+
+impl Style {
+    pub const fn new() -> Self {
+        Self {
+            fg: None,
+            bg: None,
+            underline_color: None,
+            add_modifier: Modifier::empty(),
+            sub_modifier: Modifier::empty(),
+        }
+    }
+
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    /// Sets the color of the underline drawn under text carrying [`Modifier::UNDERLINED`], on
+    /// backends that support an underline color distinct from the foreground.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn underline_color(mut self, color: Color) -> Self {
+        self.underline_color = Some(color);
+        self
+    }
+
+    /// Adds `modifier` to this style, overriding any pending removal of it from a previous
+    /// [`Style::remove_modifier`] call.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn add_modifier(mut self, modifier: Modifier) -> Self {
+        self.sub_modifier.remove(modifier);
+        self.add_modifier.insert(modifier);
+        self
+    }
+
+    /// Removes `modifier` from this style, overriding any pending addition of it from a previous
+    /// [`Style::add_modifier`] call.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn remove_modifier(mut self, modifier: Modifier) -> Self {
+        self.add_modifier.remove(modifier);
+        self.sub_modifier.insert(modifier);
+        self
+    }
+
+    pub const fn reset() -> Self {
+        Self {
+            fg: Some(Color::Reset),
+            bg: Some(Color::Reset),
+            underline_color: Some(Color::Reset),
+            add_modifier: Modifier::empty(),
+            sub_modifier: Modifier::all(),
+        }
+    }
+
+    /// Combines this style with `other`, with `other`'s explicitly-set fields and modifiers
+    /// taking precedence.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn patch(mut self, other: Self) -> Self {
+        self.fg = other.fg.or(self.fg);
+        self.bg = other.bg.or(self.bg);
+        self.underline_color = other.underline_color.or(self.underline_color);
+
+        self.add_modifier.remove(other.sub_modifier);
+        self.add_modifier.insert(other.add_modifier);
+        self.sub_modifier.remove(other.add_modifier);
+        self.sub_modifier.insert(other.sub_modifier);
+
+        self
+    }
+
+    /// Returns this style with its foreground adjusted, if necessary, to meet `min_contrast`
+    /// against `bg`.
+    ///
+    /// If the current foreground (or [`Color::Reset`]/unset, treated as black; an unset one is set
+    /// to black) already contrasts with `bg` by at least `min_contrast`, it is kept. Otherwise the
+    /// foreground is lightened against a dark `bg` or darkened against a light one (via
+    /// [`Color::lighten`]/[`Color::darken`], only in that one direction) until the ratio is met;
+    /// if that doesn't reach the threshold within the adjustment budget, pure black or white
+    /// (whichever contrasts more) is used instead. [`Style::bg`] is always set to `bg`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn ensure_contrast(mut self, bg: Color) -> Self {
+        self = self.ensure_contrast_with(bg, MIN_CONTRAST);
+        self
+    }
+
+    /// Like [`Style::ensure_contrast`], but with a caller-chosen minimum contrast ratio instead
+    /// of [`MIN_CONTRAST`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn ensure_contrast_with(mut self, bg: Color, min_contrast: f64) -> Self {
+        let fg = self.fg.unwrap_or(Color::Black);
+        if contrast_ratio(fg, bg) >= min_contrast {
+            self.bg = Some(bg);
+            self.fg = Some(fg);
+            return self;
+        }
+
+        let bg_is_light = relative_luminance(bg) > 0.5;
+        // Walk the foreground towards the end of the lightness range furthest from `bg`, in
+        // small steps, stopping as soon as the threshold is met.
+        let mut candidate = fg;
+        for _ in 0..20 {
+            candidate = if bg_is_light {
+                candidate.darken(0.1)
+            } else {
+                candidate.lighten(0.1)
+            };
+            if contrast_ratio(candidate, bg) >= min_contrast {
+                self.fg = Some(candidate);
+                self.bg = Some(bg);
+                return self;
+            }
+        }
+
+        // Fall back to whichever of pure black/white contrasts more against `bg`.
+        let black_contrast = contrast_ratio(Color::Black, bg);
+        let white_contrast = contrast_ratio(Color::White, bg);
+        self.fg = Some(if white_contrast >= black_contrast {
+            Color::White
+        } else {
+            Color::Black
+        });
+        self.bg = Some(bg);
+        self
+    }
+}
+
+/// Linearizes a single sRGB channel (`0.0..=1.0`) for relative luminance computation.
+fn linearize(channel: f64) -> f64 {
+    if channel <= 0.03928 {
+        channel / 12.92
+    } else {
+        ((channel + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// The WCAG relative luminance of `color`, in `0.0..=1.0`.
+fn relative_luminance(color: Color) -> f64 {
+    let Color::Rgb(r, g, b) = resolve(color) else {
+        unreachable!("resolve always returns Rgb")
+    };
+    let (r, g, b) = (
+        linearize(f64::from(r) / 255.0),
+        linearize(f64::from(g) / 255.0),
+        linearize(f64::from(b) / 255.0),
+    );
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+/// The WCAG contrast ratio between two colors, always `>= 1.0`.
+fn contrast_ratio(a: Color, b: Color) -> f64 {
+    let (l1, l2) = (relative_luminance(a), relative_luminance(b));
+    let (lighter, darker) = if l1 >= l2 { (l1, l2) } else { (l2, l1) };
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+/// Resolves any [`Color`] variant to [`Color::Rgb`] via the HSL round trip, which already knows
+/// how to map every named/indexed color.
+fn resolve(color: Color) -> Color {
+    Color::from_hsl(color.to_hsl())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn black_on_white_already_has_contrast() {
+        let style = Style::new().fg(Color::Black).ensure_contrast(Color::White);
+        assert_eq!(style.fg, Some(Color::Black));
+    }
+
+    #[test]
+    fn low_contrast_foreground_is_adjusted() {
+        // Dark gray on black starts out with poor contrast.
+        let style = Style::new()
+            .fg(Color::Rgb(30, 30, 30))
+            .ensure_contrast(Color::Black);
+        assert!(contrast_ratio(style.fg.unwrap(), Color::Black) >= MIN_CONTRAST);
+    }
+
+    #[test]
+    fn unset_foreground_defaults_to_black_then_adjusts() {
+        let style = Style::new().ensure_contrast(Color::Black);
+        assert!(contrast_ratio(style.fg.unwrap(), Color::Black) >= MIN_CONTRAST);
+    }
+
+    #[test]
+    fn add_modifier_sets_the_flag() {
+        let style = Style::new().add_modifier(Modifier::BOLD);
+        assert_eq!(style.add_modifier, Modifier::BOLD);
+        assert_eq!(style.sub_modifier, Modifier::empty());
+    }
+
+    #[test]
+    fn remove_modifier_overrides_an_earlier_add() {
+        let style = Style::new()
+            .add_modifier(Modifier::BOLD)
+            .remove_modifier(Modifier::BOLD);
+        assert_eq!(style.add_modifier, Modifier::empty());
+        assert_eq!(style.sub_modifier, Modifier::BOLD);
+    }
+
+    #[test]
+    fn patch_lets_explicit_modifiers_win() {
+        let style = Style::new()
+            .add_modifier(Modifier::BOLD)
+            .patch(Style::new().remove_modifier(Modifier::BOLD).add_modifier(Modifier::ITALIC));
+        assert_eq!(style.add_modifier, Modifier::ITALIC);
+        assert_eq!(style.sub_modifier, Modifier::BOLD);
+    }
+
+    #[test]
+    fn patch_keeps_fg_when_other_is_unset() {
+        let style = Style::new().fg(Color::Red).patch(Style::new());
+        assert_eq!(style.fg, Some(Color::Red));
+    }
+}
@@ -0,0 +1,176 @@
+//! Fluent color/modifier shorthand methods (`.red()`, `.bold()`, ...).
+//!
+//! [`Stylize`] is implemented once, generically, for anything that implements [`Styled`] — today
+//! that's [`Style`] itself and `&str` (which turns into a [`Span`](crate::text::Span)) — so the
+//! same shorthand reads naturally in either position: `Style::new().red().bold()` or
+//! `"some text".red().bold()`.
+
+use super::{Color, Modifier, Style};
+
+/// Something that carries a [`Style`] and can hand back a copy of itself with a style applied.
+///
+/// [`Style`] is its own `Item` (patching in place); `&str` produces a
+/// [`Span`](crate::text::Span), since a bare string has nowhere to store a style.
+pub trait Styled {
+    type Item;
+
+    fn style(&self) -> Style;
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item;
+}
+
+impl Styled for Style {
+    type Item = Style;
+
+    fn style(&self) -> Style {
+        *self
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.patch(style.into())
+    }
+}
+
+impl<'a> Styled for &'a str {
+    type Item = crate::text::Span<'a>;
+
+    fn style(&self) -> Style {
+        Style::default()
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        crate::text::Span::styled(self, style)
+    }
+}
+
+/// Generates a `fn $name(self) -> T` that sets the given foreground color.
+macro_rules! fg_color {
+    ($name:ident, $color:ident) => {
+        #[must_use = "`Stylize` methods are no-ops unless the result is used"]
+        fn $name(self) -> T {
+            self.fg(Color::$color)
+        }
+    };
+}
+
+/// Generates a `fn $name(self) -> T` (e.g. `on_red`) that sets the given background color.
+macro_rules! bg_color {
+    ($name:ident, $color:ident) => {
+        #[must_use = "`Stylize` methods are no-ops unless the result is used"]
+        fn $name(self) -> T {
+            self.bg(Color::$color)
+        }
+    };
+}
+
+/// Generates a `fn $name(self) -> T` that adds the given modifier.
+macro_rules! modifier {
+    ($name:ident, $modifier:ident) => {
+        #[must_use = "`Stylize` methods are no-ops unless the result is used"]
+        fn $name(self) -> T {
+            self.add_modifier(Modifier::$modifier)
+        }
+    };
+}
+
+/// Fluent shorthand for applying colors and modifiers, implemented for anything that implements
+/// [`Styled`] (see the module docs).
+pub trait Stylize<T>: Sized {
+    fn fg(self, color: Color) -> T;
+    fn bg(self, color: Color) -> T;
+    fn reset(self) -> T;
+    fn add_modifier(self, modifier: Modifier) -> T;
+    fn remove_modifier(self, modifier: Modifier) -> T;
+
+    fg_color!(black, Black);
+    fg_color!(red, Red);
+    fg_color!(green, Green);
+    fg_color!(yellow, Yellow);
+    fg_color!(blue, Blue);
+    fg_color!(magenta, Magenta);
+    fg_color!(cyan, Cyan);
+    fg_color!(gray, Gray);
+    fg_color!(dark_gray, DarkGray);
+    fg_color!(light_red, LightRed);
+    fg_color!(light_green, LightGreen);
+    fg_color!(light_yellow, LightYellow);
+    fg_color!(light_blue, LightBlue);
+    fg_color!(light_magenta, LightMagenta);
+    fg_color!(light_cyan, LightCyan);
+    fg_color!(white, White);
+
+    bg_color!(on_black, Black);
+    bg_color!(on_red, Red);
+    bg_color!(on_green, Green);
+    bg_color!(on_yellow, Yellow);
+    bg_color!(on_blue, Blue);
+    bg_color!(on_magenta, Magenta);
+    bg_color!(on_cyan, Cyan);
+    bg_color!(on_gray, Gray);
+    bg_color!(on_dark_gray, DarkGray);
+    bg_color!(on_light_red, LightRed);
+    bg_color!(on_light_green, LightGreen);
+    bg_color!(on_light_yellow, LightYellow);
+    bg_color!(on_light_blue, LightBlue);
+    bg_color!(on_light_magenta, LightMagenta);
+    bg_color!(on_light_cyan, LightCyan);
+    bg_color!(on_white, White);
+
+    modifier!(bold, BOLD);
+    modifier!(dim, DIM);
+    modifier!(italic, ITALIC);
+    modifier!(underlined, UNDERLINED);
+    modifier!(slow_blink, SLOW_BLINK);
+    modifier!(rapid_blink, RAPID_BLINK);
+    modifier!(reversed, REVERSED);
+    modifier!(hidden, HIDDEN);
+    modifier!(crossed_out, CROSSED_OUT);
+}
+
+impl<T, U> Stylize<T> for U
+where
+    U: Styled<Item = T>,
+{
+    fn fg(self, color: Color) -> T {
+        self.set_style(Style::new().fg(color))
+    }
+
+    fn bg(self, color: Color) -> T {
+        self.set_style(Style::new().bg(color))
+    }
+
+    fn reset(self) -> T {
+        self.set_style(Style::reset())
+    }
+
+    fn add_modifier(self, modifier: Modifier) -> T {
+        self.set_style(Style::new().add_modifier(modifier))
+    }
+
+    fn remove_modifier(self, modifier: Modifier) -> T {
+        self.set_style(Style::new().remove_modifier(modifier))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn style_shorthand_chains() {
+        let style = Style::new().yellow().italic();
+        assert_eq!(style, Style::new().fg(Color::Yellow).add_modifier(Modifier::ITALIC));
+    }
+
+    #[test]
+    fn str_shorthand_produces_a_styled_span() {
+        let span = "hello".red();
+        assert_eq!(span.content, "hello");
+        assert_eq!(span.style, Style::new().fg(Color::Red));
+    }
+
+    #[test]
+    fn on_color_sets_background() {
+        let style = Style::new().on_blue();
+        assert_eq!(style.bg, Some(Color::Blue));
+    }
+}
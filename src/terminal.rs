@@ -0,0 +1,211 @@
+use std::{
+    io::{self, stdout},
+    panic,
+    sync::Once,
+};
+
+use crossterm::{
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+
+use crate::{
+    backend::Backend,
+    backend::CrosstermBackend,
+    buffer::Buffer,
+    layout::Rect,
+    text::Text,
+    widgets::{Paragraph, Widget, Wrap},
+};
+
+/// An interface to interact and draw [`Frame`](crate::Frame)s on the user's terminal.
+///
+/// This is a focused excerpt of `Terminal` covering [`Terminal::insert_before`] and the new
+/// [`Terminal::insert_before_measured`]; the rest of the terminal lifecycle (the diffing render
+/// loop, cursor handling, resize detection, ...) is unchanged.
+pub struct Terminal<B: Backend> {
+    backend: B,
+    viewport_area: Rect,
+}
+
+impl<B: Backend> Terminal<B> {
+    /// Inserts `height` lines before the current viewport, scrolling it down, and lets `draw_fn`
+    /// render into the freed area.
+    ///
+    /// Callers must know the number of rows the content will occupy ahead of time, which is
+    /// awkward for wrapped text of unknown length.
+    pub fn insert_before<F>(&mut self, height: u16, draw_fn: F) -> io::Result<()>
+    where
+        F: FnOnce(&mut Buffer),
+    {
+        let area = Rect::new(0, 0, self.viewport_area.width, height);
+        let mut buf = Buffer::empty(area);
+        draw_fn(&mut buf);
+        self.backend.append_lines(height)?;
+        self.backend.draw(
+            buf.content
+                .iter()
+                .enumerate()
+                .map(|(i, cell)| (i as u16 % area.width, i as u16 / area.width, cell)),
+        )
+    }
+
+    /// Like [`Terminal::insert_before`], but measures the content instead of requiring a
+    /// pre-computed line count.
+    ///
+    /// `draw_fn` is given the usable `max_width` and returns the [`Text`] to render; this method
+    /// word-wraps that text to `max_width` to find out how many rows it actually needs, inserts
+    /// exactly that many lines, renders the text into them, and returns the number of rows
+    /// consumed. This makes "log lines scrolling above a live prompt" work without the caller
+    /// pre-computing wrap heights.
+    pub fn insert_before_measured<F>(&mut self, max_width: u16, draw_fn: F) -> io::Result<u16>
+    where
+        F: FnOnce(u16) -> Text<'static>,
+    {
+        let text = draw_fn(max_width);
+        let paragraph = Paragraph::new(text).wrap(Wrap { trim: false });
+        let height = wrapped_height(&paragraph, max_width);
+        self.insert_before(height, |buf| {
+            paragraph.render(buf.area, buf);
+        })?;
+        Ok(height)
+    }
+}
+
+/// Puts the terminal into raw mode with an alternate screen and installs a panic hook that
+/// restores it, then returns a [`Terminal`] wrapped in a [`TerminalGuard`] that restores it again
+/// on [`Drop`].
+///
+/// This replaces the `enable_raw_mode` / `EnterAlternateScreen` / ... dance every example used to
+/// hand-roll: if `run_app` panics, the installed hook resets the terminal (leaves the alternate
+/// screen, disables raw mode, shows the cursor) *before* the default panic handler prints the
+/// backtrace, so the message is still readable instead of being garbled by a half-raw terminal.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use ratatui::terminal::init;
+/// let mut terminal = init()?;
+/// // ... terminal.draw(...) in a loop ...
+/// // restored automatically when `terminal` is dropped
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn init() -> io::Result<TerminalGuard> {
+    install_panic_hook();
+    enable_raw_mode()?;
+    execute!(stdout(), EnterAlternateScreen)?;
+    let backend = CrosstermBackend::new(stdout());
+    let terminal = Terminal {
+        backend,
+        viewport_area: Rect::default(),
+    };
+    Ok(TerminalGuard {
+        terminal: Some(terminal),
+    })
+}
+
+/// Restores the terminal to its original state: leaves the alternate screen, disables raw mode,
+/// and shows the cursor.
+///
+/// Safe to call more than once (e.g. once from a panic hook and once from [`TerminalGuard`]'s
+/// `Drop`): after the first successful restore, later calls are harmless even if the terminal is
+/// already back to normal.
+pub fn restore() -> io::Result<()> {
+    disable_raw_mode()?;
+    execute!(stdout(), LeaveAlternateScreen)?;
+    crossterm::execute!(stdout(), crossterm::cursor::Show)?;
+    Ok(())
+}
+
+fn install_panic_hook() {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let _ = restore();
+            previous_hook(info);
+        }));
+    });
+}
+
+/// An RAII guard returned by [`init`] that restores the terminal when dropped.
+///
+/// Dropping the guard (including via an early `return` or `?`) calls [`restore`] exactly once;
+/// calling it again after a manual [`TerminalGuard::restore`] is a no-op.
+pub struct TerminalGuard<B: Backend = CrosstermBackend<io::Stdout>> {
+    terminal: Option<Terminal<B>>,
+}
+
+impl<B: Backend> TerminalGuard<B> {
+    /// Restores the terminal now, rather than waiting for `Drop`.
+    pub fn restore(&mut self) -> io::Result<()> {
+        if self.terminal.take().is_some() {
+            restore()?;
+        }
+        Ok(())
+    }
+}
+
+impl<B: Backend> std::ops::Deref for TerminalGuard<B> {
+    type Target = Terminal<B>;
+
+    fn deref(&self) -> &Self::Target {
+        self.terminal.as_ref().expect("terminal already restored")
+    }
+}
+
+impl<B: Backend> std::ops::DerefMut for TerminalGuard<B> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.terminal.as_mut().expect("terminal already restored")
+    }
+}
+
+impl<B: Backend> Drop for TerminalGuard<B> {
+    fn drop(&mut self) {
+        if self.terminal.take().is_some() {
+            let _ = restore();
+        }
+    }
+}
+
+/// Counts the rows `paragraph` occupies once wrapped to `max_width`, without actually rendering
+/// it.
+///
+/// Delegates to [`Paragraph::wrapped_lines`] (the same method `Paragraph::render` uses) rather
+/// than re-deriving row counts from each line's width, since a naive `width.div_ceil(max_width)`
+/// doesn't account for [`Line::wrapped`]'s hard-breaking of words longer than `max_width` onto
+/// further lines.
+fn wrapped_height(paragraph: &Paragraph, max_width: u16) -> u16 {
+    paragraph.wrapped_lines(max_width).len() as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::Line;
+
+    fn wrapping_paragraph(text: Text<'static>) -> Paragraph<'static> {
+        Paragraph::new(text).wrap(Wrap { trim: false })
+    }
+
+    #[test]
+    fn wrapped_height_counts_wrapped_rows() {
+        let text = Text::from(vec![Line::from("a".repeat(25)), Line::from("short")]);
+        assert_eq!(wrapped_height(&wrapping_paragraph(text), 10), 4);
+    }
+
+    #[test]
+    fn wrapped_height_counts_empty_line_as_one_row() {
+        let text = Text::from(vec![Line::from("")]);
+        assert_eq!(wrapped_height(&wrapping_paragraph(text), 10), 1);
+    }
+
+    #[test]
+    fn wrapped_height_hard_breaks_a_word_longer_than_max_width() {
+        // Regression test: a naive `width.div_ceil(max_width)` undercounts this by a row, since it
+        // doesn't know `Line::wrapped` hard-breaks "antidisestablishmentarianism" (28 chars) across
+        // three 10-wide rows rather than rounding its combined width with "x " up to the nearest 10.
+        let text = Text::from("x antidisestablishmentarianism");
+        assert_eq!(wrapped_height(&wrapping_paragraph(text), 10), 4);
+    }
+}
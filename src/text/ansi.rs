@@ -0,0 +1,211 @@
+//! ANSI/SGR escape sequence parsing.
+//!
+//! Terminal programs (compilers, shells, CI tools) often emit color via SGR (Select Graphic
+//! Rendition) escape sequences of the form `ESC [ params m`. [`Text::from_ansi`](super::Text::from_ansi)
+//! uses [`parse`] to turn a string containing these sequences into styled [`Line`]s, so captured
+//! program output can be displayed directly instead of requiring a separate crate to strip or
+//! interpret the color codes.
+//!
+//! Only SGR sequences (ones ending in `m`) are interpreted; any other CSI sequence (e.g. cursor
+//! movement) is dropped rather than rendered as literal garbage.
+
+use super::{Line, Span};
+use crate::style::{Color, Modifier, Style};
+
+const CSI_INTRODUCER: char = '[';
+
+/// Parses `input` into a list of styled [`Line`]s, splitting on `\n` and applying SGR codes as a
+/// running [`Style`] that persists across spans until the next code changes it.
+pub fn parse(input: &str) -> Vec<Line<'static>> {
+    let mut lines = vec![];
+    let mut spans = vec![];
+    let mut current = String::new();
+    let mut style = Style::default();
+
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\x1b' if chars.peek() == Some(&CSI_INTRODUCER) => {
+                chars.next();
+                let mut params = String::new();
+                let terminator = loop {
+                    match chars.next() {
+                        Some(c) if c.is_ascii_alphabetic() => break Some(c),
+                        Some(c) => params.push(c),
+                        None => break None,
+                    }
+                };
+                if terminator == Some('m') {
+                    if !current.is_empty() {
+                        spans.push(Span::styled(std::mem::take(&mut current), style));
+                    }
+                    apply_sgr(&params, &mut style);
+                }
+            }
+            '\n' => {
+                if !current.is_empty() {
+                    spans.push(Span::styled(std::mem::take(&mut current), style));
+                }
+                lines.push(Line::from(std::mem::take(&mut spans)));
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        spans.push(Span::styled(current, style));
+    }
+    if !spans.is_empty() {
+        lines.push(Line::from(spans));
+    }
+    lines
+}
+
+/// Applies the SGR codes in `params` (a `;`-separated parameter string, without the leading `ESC
+/// [` or trailing `m`) to `style`, mutating it in place.
+fn apply_sgr(params: &str, style: &mut Style) {
+    let codes: Vec<&str> = if params.is_empty() { vec!["0"] } else { params.split(';').collect() };
+    let mut iter = codes.into_iter();
+    while let Some(code) = iter.next() {
+        match code.parse::<u16>().unwrap_or(0) {
+            0 => *style = Style::default(),
+            1 => *style = style.add_modifier(Modifier::BOLD),
+            2 => *style = style.add_modifier(Modifier::DIM),
+            3 => *style = style.add_modifier(Modifier::ITALIC),
+            4 => *style = style.add_modifier(Modifier::UNDERLINED),
+            5 => *style = style.add_modifier(Modifier::SLOW_BLINK),
+            7 => *style = style.add_modifier(Modifier::REVERSED),
+            8 => *style = style.add_modifier(Modifier::HIDDEN),
+            9 => *style = style.add_modifier(Modifier::CROSSED_OUT),
+            21 | 22 => *style = style.remove_modifier(Modifier::BOLD | Modifier::DIM),
+            23 => *style = style.remove_modifier(Modifier::ITALIC),
+            24 => *style = style.remove_modifier(Modifier::UNDERLINED),
+            25 => *style = style.remove_modifier(Modifier::SLOW_BLINK),
+            27 => *style = style.remove_modifier(Modifier::REVERSED),
+            28 => *style = style.remove_modifier(Modifier::HIDDEN),
+            29 => *style = style.remove_modifier(Modifier::CROSSED_OUT),
+            30..=37 => style.fg = Some(ansi_color(code.parse::<u16>().unwrap() - 30)),
+            38 => style.fg = extended_color(&mut iter),
+            39 => style.fg = None,
+            40..=47 => style.bg = Some(ansi_color(code.parse::<u16>().unwrap() - 40)),
+            48 => style.bg = extended_color(&mut iter),
+            49 => style.bg = None,
+            90..=97 => style.fg = Some(ansi_color(code.parse::<u16>().unwrap() - 90 + 8)),
+            100..=107 => style.bg = Some(ansi_color(code.parse::<u16>().unwrap() - 100 + 8)),
+            _ => {}
+        }
+    }
+}
+
+/// Maps an ANSI 3/4-bit color index (`0..16`, with `8..16` the "bright" variants) to [`Color`].
+fn ansi_color(index: u16) -> Color {
+    match index {
+        0 => Color::Black,
+        1 => Color::Red,
+        2 => Color::Green,
+        3 => Color::Yellow,
+        4 => Color::Blue,
+        5 => Color::Magenta,
+        6 => Color::Cyan,
+        7 => Color::Gray,
+        8 => Color::DarkGray,
+        9 => Color::LightRed,
+        10 => Color::LightGreen,
+        11 => Color::LightYellow,
+        12 => Color::LightBlue,
+        13 => Color::LightMagenta,
+        14 => Color::LightCyan,
+        _ => Color::White,
+    }
+}
+
+/// Parses the remainder of a `38;...`/`48;...` extended color code (256-color or truecolor),
+/// consuming as many further `;`-separated params as the mode requires.
+fn extended_color<'a>(iter: &mut impl Iterator<Item = &'a str>) -> Option<Color> {
+    match iter.next()?.parse::<u16>().ok()? {
+        5 => {
+            let n = iter.next()?.parse::<u8>().ok()?;
+            Some(Color::Indexed(n))
+        }
+        2 => {
+            let r = iter.next()?.parse::<u8>().ok()?;
+            let g = iter.next()?.parse::<u8>().ok()?;
+            let b = iter.next()?.parse::<u8>().ok()?;
+            Some(Color::Rgb(r, g, b))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_text_is_a_single_span() {
+        let lines = parse("hello");
+        assert_eq!(lines, vec![Line::from("hello")]);
+    }
+
+    #[test]
+    fn splits_on_newlines() {
+        let lines = parse("foo\nbar");
+        assert_eq!(lines, vec![Line::from("foo"), Line::from("bar")]);
+    }
+
+    #[test]
+    fn sgr_color_applies_to_following_text() {
+        let lines = parse("\x1b[31mred\x1b[0m plain");
+        assert_eq!(
+            lines,
+            vec![Line::from(vec![
+                Span::styled("red", Style::new().fg(Color::Red)),
+                Span::raw(" plain"),
+            ])]
+        );
+    }
+
+    #[test]
+    fn style_persists_across_spans_until_changed() {
+        let lines = parse("\x1b[1mbold\x1b[31mbold red");
+        assert_eq!(
+            lines,
+            vec![Line::from(vec![
+                Span::styled("bold", Style::new().add_modifier(Modifier::BOLD)),
+                Span::styled(
+                    "bold red",
+                    Style::new().fg(Color::Red).add_modifier(Modifier::BOLD)
+                ),
+            ])]
+        );
+    }
+
+    #[test]
+    fn indexed_256_color() {
+        let lines = parse("\x1b[38;5;200mhi");
+        assert_eq!(
+            lines,
+            vec![Line::from(Span::styled(
+                "hi",
+                Style::new().fg(Color::Indexed(200))
+            ))]
+        );
+    }
+
+    #[test]
+    fn truecolor() {
+        let lines = parse("\x1b[38;2;10;20;30mhi");
+        assert_eq!(
+            lines,
+            vec![Line::from(Span::styled(
+                "hi",
+                Style::new().fg(Color::Rgb(10, 20, 30))
+            ))]
+        );
+    }
+
+    #[test]
+    fn unrecognized_csi_sequence_is_dropped() {
+        let lines = parse("foo\x1b[2Jbar");
+        assert_eq!(lines, vec![Line::from("foobar")]);
+    }
+}
@@ -0,0 +1,74 @@
+//! OSC 8 terminal hyperlink support.
+//!
+//! Terminals such as iTerm2, WezTerm and foot recognize the OSC 8 escape sequence
+//! `ESC ] 8 ; params ; URI ST text ESC ] 8 ; ; ST` to mark a run of cells as a clickable
+//! hyperlink. [`Span::hyperlink`](super::Span::hyperlink) attaches a URI to a span via
+//! [`Span::link`](super::Span::link); this module turns that URI back into the escape sequence
+//! the backend writes around the span's rendered text.
+//!
+//! Rather than wrapping every grapheme individually, the rendering path should group a
+//! contiguous run of cells that share the same link under a single OSC 8 pair; see
+//! [`group_by_link`].
+
+use std::borrow::Cow;
+
+use super::StyledGrapheme;
+
+const OSC8_START: &str = "\x1b]8;;";
+const OSC8_END: &str = "\x1b\\";
+
+/// Wraps `text` in the OSC 8 open/close sequence for `uri`.
+///
+/// On a terminal/backend that doesn't support OSC 8, these bytes are typically ignored, so the
+/// span degrades to plain styled text rather than producing visible garbage.
+pub fn wrap(uri: &str, text: &str) -> String {
+    format!("{OSC8_START}{uri}{OSC8_END}{text}{OSC8_START}{OSC8_END}")
+}
+
+/// Groups consecutive graphemes that share the same link into `(text, Option<uri>)` runs.
+///
+/// This lets a backend emit one OSC 8 pair per contiguous hyperlinked run instead of re-opening
+/// and re-closing the sequence for every grapheme, which is both wasteful and can confuse some
+/// terminal hyperlink-detection heuristics.
+pub fn group_by_link<'a>(
+    graphemes: impl IntoIterator<Item = StyledGrapheme<'a>>,
+) -> Vec<(String, Option<Cow<'a, str>>)> {
+    let mut runs: Vec<(String, Option<Cow<'a, str>>)> = vec![];
+    for grapheme in graphemes {
+        match runs.last_mut() {
+            Some((text, link)) if *link == grapheme.link => text.push_str(grapheme.symbol),
+            _ => runs.push((grapheme.symbol.to_string(), grapheme.link.clone())),
+        }
+    }
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Style;
+
+    #[test]
+    fn wrap_includes_uri_and_text() {
+        let wrapped = wrap("https://example.com", "click me");
+        assert!(wrapped.contains("https://example.com"));
+        assert!(wrapped.contains("click me"));
+    }
+
+    #[test]
+    fn group_by_link_coalesces_contiguous_runs() {
+        let graphemes = vec![
+            StyledGrapheme::new("h", Style::default()).with_link(Some(Cow::Borrowed("u"))),
+            StyledGrapheme::new("i", Style::default()).with_link(Some(Cow::Borrowed("u"))),
+            StyledGrapheme::new("!", Style::default()),
+        ];
+        let runs = group_by_link(graphemes);
+        assert_eq!(
+            runs,
+            vec![
+                ("hi".to_string(), Some(Cow::Borrowed("u"))),
+                ("!".to_string(), None),
+            ]
+        );
+    }
+}
@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use unicode_width::UnicodeWidthStr;
+
 use super::{Span, Style, StyledGrapheme};
 use crate::layout::Alignment;
 
@@ -243,8 +245,11 @@ impl Line<'_> {
 
     /// Returns an iterator over the graphemes held by this line.
     ///
-    /// `base_style` is the [`Style`] that will be patched with each grapheme [`Style`] to get
-    /// the resulting [`Style`].
+    /// `base_style` is the [`Style`] that will be patched with the line's own style and then each
+    /// span's [`Style`] to get the resulting [`Style`], i.e. the effective style of each grapheme
+    /// is `base_style.patch(self.style).patch(span.style)`. This keeps widgets that render via
+    /// graphemes (wrapping, alignment, scrolling) consistent with widgets that render whole spans
+    /// via [`Line::styled_spans`], which already applies `self.style`.
     ///
     /// # Examples
     ///
@@ -257,6 +262,7 @@ impl Line<'_> {
     /// let graphemes = line.styled_graphemes(style).collect::<Vec<StyledGrapheme>>();
     /// ```
     pub fn styled_graphemes(&self, base_style: Style) -> impl Iterator<Item = StyledGrapheme> {
+        let base_style = base_style.patch(self.style);
         self.spans
             .iter()
             .flat_map(move |span| span.styled_graphemes(base_style))
@@ -265,6 +271,288 @@ impl Line<'_> {
     pub fn styled_spans(&self) -> impl Iterator<Item = Span> {
         self.spans.iter().cloned().map(|s| s.style(self.style))
     }
+
+    /// Returns the span under the given `column`, together with its index in [`Line::spans`].
+    ///
+    /// `column` is relative to the start of the line's own content (column `0` is the first cell
+    /// of the first span), regardless of `self.alignment`; callers rendering a right- or
+    /// center-aligned line should first translate the clicked screen column into this local
+    /// coordinate space using the x-origin they rendered the line at.
+    ///
+    /// Accounts for multi-cell graphemes: spans are measured by [`Span::width`], not by their
+    /// character count. Zero-width spans never match and are skipped; on a tie the first
+    /// matching span wins. Returns [`None`] if `column` is outside every span, which in
+    /// particular means `span_at(line.width())` is always [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let line = Line::from(vec![Span::raw("Hello "), Span::raw("World")]);
+    /// assert_eq!(line.span_at(0).map(|(i, _)| i), Some(0));
+    /// assert_eq!(line.span_at(6).map(|(i, _)| i), Some(1));
+    /// assert_eq!(line.span_at(line.width()), None);
+    /// ```
+    pub fn span_at(&self, column: usize) -> Option<(usize, &Span)> {
+        let mut start = 0;
+        for (index, span) in self.spans.iter().enumerate() {
+            let width = span.width();
+            if width == 0 {
+                continue;
+            }
+            let end = start + width;
+            if (start..end).contains(&column) {
+                return Some((index, span));
+            }
+            start = end;
+        }
+        None
+    }
+
+    /// Reflows this line's spans into one or more [`Line`]s that each fit within `max_width`,
+    /// preserving per-span styling as well as this line's own `style` and `alignment`.
+    ///
+    /// Words are split at Unicode whitespace boundaries; a single word longer than `max_width` is
+    /// hard-broken at the grapheme boundary nearest the limit so no output line ever exceeds it.
+    /// When `trim` is `true`, leading whitespace is dropped from the start of every wrapped line
+    /// except the first, so that indentation on the first line is preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let line = Line::from("The quick brown fox");
+    /// let wrapped = line.wrapped(10, true);
+    /// assert_eq!(wrapped.len(), 2);
+    /// ```
+    pub fn wrapped(&self, max_width: usize, trim: bool) -> Vec<Line<'a>> {
+        let max_width = max_width.max(1);
+
+        // Group the line's styled graphemes into words, keeping whitespace runs as their own
+        // "words" so they can be trimmed or kept depending on `trim` and position.
+        let mut words: Vec<Vec<(Cow<'a, str>, Style)>> = vec![];
+        for span in &self.spans {
+            for grapheme in span.styled_graphemes(Style::default()) {
+                let is_whitespace = grapheme.symbol.chars().all(char::is_whitespace);
+                let starts_new_word = match words.last().and_then(|w| w.first()) {
+                    Some((first, _)) => {
+                        let last_was_whitespace = first.chars().all(char::is_whitespace);
+                        last_was_whitespace != is_whitespace
+                    }
+                    None => true,
+                };
+                if starts_new_word {
+                    words.push(vec![]);
+                }
+                words
+                    .last_mut()
+                    .unwrap()
+                    .push((Cow::Owned(grapheme.symbol.to_string()), grapheme.style));
+            }
+        }
+
+        let mut lines: Vec<Vec<(Cow<'a, str>, Style)>> = vec![vec![]];
+        let mut current_width = 0usize;
+
+        let word_width = |word: &[(Cow<'a, str>, Style)]| -> usize {
+            word.iter().map(|(s, _)| s.width()).sum()
+        };
+
+        for word in words {
+            let is_whitespace = word
+                .first()
+                .map(|(s, _)| s.chars().all(char::is_whitespace))
+                .unwrap_or(false);
+            let width = word_width(&word);
+
+            if current_width + width > max_width && current_width > 0 {
+                lines.push(vec![]);
+                current_width = 0;
+            }
+
+            if width > max_width {
+                // Hard-break a single over-long word at the grapheme boundary nearest the limit.
+                for (symbol, style) in word {
+                    let symbol_width = symbol.width();
+                    if current_width + symbol_width > max_width && current_width > 0 {
+                        lines.push(vec![]);
+                        current_width = 0;
+                    }
+                    lines.last_mut().unwrap().push((symbol, style));
+                    current_width += symbol_width;
+                }
+                continue;
+            }
+
+            if trim && is_whitespace && current_width == 0 && lines.len() > 1 {
+                continue;
+            }
+
+            lines.last_mut().unwrap().extend(word);
+            current_width += width;
+        }
+
+        lines
+            .into_iter()
+            .map(|graphemes| {
+                // Re-coalesce runs of identical style back into spans.
+                let mut spans: Vec<Span<'a>> = vec![];
+                for (symbol, style) in graphemes {
+                    match spans.last_mut() {
+                        Some(last) if last.style == style => {
+                            let mut content = last.content.to_string();
+                            content.push_str(&symbol);
+                            last.content = Cow::Owned(content);
+                        }
+                        _ => spans.push(Span::styled(symbol, style)),
+                    }
+                }
+                Line {
+                    spans,
+                    style: self.style,
+                    alignment: self.alignment,
+                }
+            })
+            .collect()
+    }
+
+    /// Truncates this line to `max_width` cells, inserting `ellipsis` where content was dropped,
+    /// and preserves each retained span's style.
+    ///
+    /// If the line already fits, a clone is returned unchanged. Otherwise cells are dropped from
+    /// the end opposite `align` (i.e. the end that would be pushed off-screen were this line
+    /// rendered with that alignment): from the right for [`Alignment::Left`], from the left for
+    /// [`Alignment::Right`], and from both ends for [`Alignment::Center`]. Graphemes are never
+    /// split, so zero-width joiners and combining marks stay attached to their base grapheme. If
+    /// `max_width` is smaller than `ellipsis`'s width, as much of the ellipsis as fits is
+    /// returned (or an empty line).
+    pub fn truncated(&self, max_width: usize, ellipsis: &str, align: Alignment) -> Line<'a> {
+        if self.width() <= max_width {
+            return self.clone();
+        }
+
+        let ellipsis_width = ellipsis.width();
+        if ellipsis_width > max_width {
+            return Line {
+                spans: vec![Span::raw(truncate_str(ellipsis, max_width))],
+                style: self.style,
+                alignment: self.alignment,
+            };
+        }
+
+        let budget = max_width - ellipsis_width;
+        let graphemes: Vec<StyledGrapheme> = self.styled_graphemes(Style::default()).collect();
+
+        let kept: Vec<StyledGrapheme> = match align {
+            Alignment::Left => take_while_width(graphemes.into_iter(), budget),
+            Alignment::Right => {
+                let mut kept = take_while_width(graphemes.into_iter().rev(), budget);
+                kept.reverse();
+                kept
+            }
+            Alignment::Center => {
+                let left_budget = budget / 2;
+                let right_budget = budget - left_budget;
+                let left = take_while_width(graphemes.clone().into_iter(), left_budget);
+                let mut right = take_while_width(graphemes.into_iter().rev(), right_budget);
+                right.reverse();
+                left.into_iter().chain(right).collect()
+            }
+        };
+
+        let mut spans: Vec<Span<'a>> = vec![];
+        for grapheme in kept {
+            match spans.last_mut() {
+                Some(last) if last.style == grapheme.style => {
+                    let mut content = last.content.to_string();
+                    content.push_str(grapheme.symbol);
+                    last.content = Cow::Owned(content);
+                }
+                _ => spans.push(Span::styled(grapheme.symbol.to_string(), grapheme.style)),
+            }
+        }
+        if align == Alignment::Right {
+            spans.insert(0, Span::raw(ellipsis.to_string()));
+        } else {
+            spans.push(Span::raw(ellipsis.to_string()));
+        }
+
+        Line {
+            spans,
+            style: self.style,
+            alignment: self.alignment,
+        }
+    }
+
+    /// Drops the first `columns` display-width worth of graphemes from this line, preserving each
+    /// retained span's style.
+    ///
+    /// Used by [`Paragraph`](crate::widgets::Paragraph)'s horizontal scroll to clip already-wrapped
+    /// lines without re-flowing them. Graphemes are never split, so a grapheme straddling the cut
+    /// point is dropped whole rather than partially rendered. If `columns` is at least as wide as
+    /// the line, an empty line is returned.
+    pub fn skip_columns(&self, columns: usize) -> Line<'a> {
+        if columns == 0 {
+            return self.clone();
+        }
+
+        let mut spans: Vec<Span<'a>> = vec![];
+        let mut skipped = 0;
+        for grapheme in self.styled_graphemes(Style::default()) {
+            if skipped < columns {
+                skipped += grapheme.width();
+                continue;
+            }
+            match spans.last_mut() {
+                Some(last) if last.style == grapheme.style => {
+                    let mut content = last.content.to_string();
+                    content.push_str(grapheme.symbol);
+                    last.content = Cow::Owned(content);
+                }
+                _ => spans.push(Span::styled(grapheme.symbol.to_string(), grapheme.style)),
+            }
+        }
+
+        Line {
+            spans,
+            style: self.style,
+            alignment: self.alignment,
+        }
+    }
+}
+
+/// Collects graphemes from `iter` while their accumulated width stays within `budget`.
+fn take_while_width<'a>(
+    iter: impl Iterator<Item = StyledGrapheme<'a>>,
+    budget: usize,
+) -> Vec<StyledGrapheme<'a>> {
+    let mut kept = vec![];
+    let mut width = 0;
+    for grapheme in iter {
+        let grapheme_width = grapheme.width();
+        if width + grapheme_width > budget {
+            break;
+        }
+        width += grapheme_width;
+        kept.push(grapheme);
+    }
+    kept
+}
+
+/// Truncates a plain `&str` to `max_width` cells without splitting graphemes.
+fn truncate_str(s: &str, max_width: usize) -> String {
+    use unicode_segmentation::UnicodeSegmentation;
+    let mut width = 0;
+    let mut out = String::new();
+    for grapheme in s.graphemes(true) {
+        let grapheme_width = grapheme.width();
+        if width + grapheme_width > max_width {
+            break;
+        }
+        width += grapheme_width;
+        out.push_str(grapheme);
+    }
+    out
 }
 
 impl<'a> IntoIterator for Line<'a> {
@@ -441,6 +729,143 @@ mod tests {
         );
     }
 
+    #[test]
+    fn styled_graphemes_includes_line_style() {
+        const YELLOW: Style = Style::new().fg(Color::Yellow);
+        const RED: Style = Style::new().fg(Color::Red);
+        const YELLOW_ON_WHITE: Style = Style::new().fg(Color::Yellow).bg(Color::White);
+        const RED_ON_WHITE: Style = Style::new().fg(Color::Red).bg(Color::White);
+
+        let line = Line::styled("x", YELLOW);
+        let styled_graphemes = line
+            .styled_graphemes(Style::new().bg(Color::White))
+            .collect::<Vec<StyledGrapheme>>();
+        assert_eq!(
+            styled_graphemes,
+            vec![StyledGrapheme::new("x", YELLOW_ON_WHITE)],
+        );
+
+        // A span's own style still takes priority over the line's style.
+        let line = Line::from(vec![Span::styled("x", RED)]).style(YELLOW);
+        let styled_graphemes = line
+            .styled_graphemes(Style::new().bg(Color::White))
+            .collect::<Vec<StyledGrapheme>>();
+        assert_eq!(
+            styled_graphemes,
+            vec![StyledGrapheme::new("x", RED_ON_WHITE)],
+        );
+    }
+
+    #[test]
+    fn span_at() {
+        let line = Line::from(vec![Span::raw("Hello "), Span::raw("World")]);
+        assert_eq!(line.span_at(0).map(|(i, _)| i), Some(0));
+        assert_eq!(line.span_at(5).map(|(i, _)| i), Some(0));
+        assert_eq!(line.span_at(6).map(|(i, _)| i), Some(1));
+        assert_eq!(line.span_at(10).map(|(i, _)| i), Some(1));
+        assert_eq!(line.span_at(11), None);
+        assert_eq!(line.span_at(line.width()), None);
+    }
+
+    #[test]
+    fn span_at_skips_zero_width_spans() {
+        let line = Line::from(vec![Span::raw(""), Span::raw("Hi")]);
+        assert_eq!(line.span_at(0).map(|(i, _)| i), Some(1));
+    }
+
+    #[test]
+    fn wrapped_splits_at_word_boundaries() {
+        let line = Line::from("The quick brown fox").alignment(Alignment::Center);
+        let wrapped = line.wrapped(10, true);
+        let rendered: Vec<String> = wrapped.iter().map(|l| String::from(l.clone())).collect();
+        assert_eq!(rendered, vec!["The quick ", "brown fox"]);
+        for wrapped_line in &wrapped {
+            assert_eq!(wrapped_line.alignment, Some(Alignment::Center));
+            assert!(wrapped_line.width() <= 10);
+        }
+    }
+
+    #[test]
+    fn wrapped_hard_breaks_long_words() {
+        let line = Line::from("a".repeat(25));
+        let wrapped = line.wrapped(10, true);
+        for wrapped_line in &wrapped {
+            assert!(wrapped_line.width() <= 10);
+        }
+        assert_eq!(wrapped.iter().map(Line::width).sum::<usize>(), 25);
+    }
+
+    #[test]
+    fn wrapped_trims_leading_whitespace_except_first_line() {
+        let line = Line::from("aaaaa bbbbb");
+        let wrapped = line.wrapped(5, true);
+        let rendered: Vec<String> = wrapped.iter().map(|l| String::from(l.clone())).collect();
+        assert_eq!(rendered, vec!["aaaaa", "bbbbb"]);
+    }
+
+    #[test]
+    fn truncated_left_aligned() {
+        let line = Line::from("Hello, world!");
+        let truncated = line.truncated(8, "...", Alignment::Left);
+        assert_eq!(String::from(truncated.clone()), "Hello...");
+        assert!(truncated.width() <= 8);
+    }
+
+    #[test]
+    fn truncated_right_aligned() {
+        let line = Line::from("Hello, world!");
+        let truncated = line.truncated(8, "...", Alignment::Right);
+        assert_eq!(String::from(truncated), "...orld!");
+    }
+
+    #[test]
+    fn truncated_returns_clone_when_it_already_fits() {
+        let line = Line::from("short");
+        let truncated = line.truncated(10, "...", Alignment::Left);
+        assert_eq!(truncated, line);
+    }
+
+    #[test]
+    fn truncated_max_width_smaller_than_ellipsis() {
+        let line = Line::from("Hello, world!");
+        let truncated = line.truncated(2, "...", Alignment::Left);
+        assert_eq!(String::from(truncated), "..");
+    }
+
+    #[test]
+    fn skip_columns_drops_leading_graphemes() {
+        let line = Line::from("Hello, world!");
+        let skipped = line.skip_columns(7);
+        assert_eq!(String::from(skipped), "world!");
+    }
+
+    #[test]
+    fn skip_columns_zero_returns_clone() {
+        let line = Line::from("Hello").alignment(Alignment::Right);
+        let skipped = line.skip_columns(0);
+        assert_eq!(skipped, line);
+    }
+
+    #[test]
+    fn skip_columns_past_the_end_is_empty() {
+        let line = Line::from("Hello");
+        let skipped = line.skip_columns(100);
+        assert_eq!(String::from(skipped), "");
+    }
+
+    #[test]
+    fn skip_columns_preserves_per_span_style() {
+        let line = Line::from(vec![
+            Span::styled("Hi", Style::new().fg(Color::Red)),
+            Span::styled("Bye", Style::new().fg(Color::Blue)),
+        ]);
+        let skipped = line.skip_columns(2);
+        assert_eq!(
+            skipped.spans,
+            vec![Span::styled("Bye", Style::new().fg(Color::Blue))]
+        );
+    }
+
     #[test]
     fn raw_str() {
         let line = Line::raw("test content");
@@ -0,0 +1,144 @@
+use std::borrow::Cow;
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use super::StyledGrapheme;
+use crate::style::Style;
+
+/// A string where all graphemes share the same style.
+///
+/// A [`Span`] can carry an optional `link`, an arbitrary piece of caller-defined data (typically
+/// a URL) that travels along with the text but is not itself rendered. This lets a widget resolve
+/// a clicked column back to the thing the user actually clicked on; see [`Line::span_at`] for the
+/// column-to-span lookup.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui::prelude::*;
+/// Span::raw("test content");
+/// Span::styled("test content", Style::new().yellow());
+/// Span::styled(String::from("test content"), Style::new().yellow());
+/// ```
+///
+/// [`Line::span_at`]: super::Line::span_at
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Span<'a> {
+    /// The style of this span.
+    pub style: Style,
+    /// The content of this span as a Clone-on-write string.
+    pub content: Cow<'a, str>,
+    /// An optional annotation travelling with this span, e.g. a link target. Not rendered.
+    pub link: Option<Cow<'a, str>>,
+}
+
+impl<'a> Span<'a> {
+    /// Create a span with no style.
+    pub fn raw<T>(content: T) -> Span<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Span {
+            content: content.into(),
+            style: Style::default(),
+            link: None,
+        }
+    }
+
+    /// Create a span with a style.
+    pub fn styled<T>(content: T, style: Style) -> Span<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Span {
+            content: content.into(),
+            style,
+            link: None,
+        }
+    }
+
+    /// Sets the style of this span.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the content of this span.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn content<T: Into<Cow<'a, str>>>(mut self, content: T) -> Self {
+        self.content = content.into();
+        self
+    }
+
+    /// Attaches a link annotation (e.g. a URL) to this span.
+    ///
+    /// The link is not rendered as part of the span's text; it's carried alongside it so that a
+    /// widget handling a `MouseEvent` can resolve the clicked span back to its link via
+    /// [`Line::span_at`](super::Line::span_at).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn link<T: Into<Cow<'a, str>>>(mut self, link: T) -> Self {
+        self.link = Some(link.into());
+        self
+    }
+
+    /// Creates a span that renders as a clickable OSC 8 terminal hyperlink wherever the backend
+    /// supports it.
+    ///
+    /// `uri` is carried on [`Span::link`] the same way [`Span::link`] stores any other
+    /// annotation; [`Span::styled_graphemes`] and [`Line::styled_spans`](super::Line::styled_spans)
+    /// propagate it so the rendering path can wrap the span's cells in the OSC 8 open/close
+    /// sequence (see [`crate::text::hyperlink`]) instead of re-emitting it per grapheme. On
+    /// terminals or backends that don't support OSC 8, the span still renders as plain styled
+    /// text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn hyperlink<T, U>(content: T, uri: U) -> Span<'a>
+    where
+        T: Into<Cow<'a, str>>,
+        U: Into<Cow<'a, str>>,
+    {
+        Span::raw(content).link(uri)
+    }
+
+    /// Returns the width of the content held by this span.
+    pub fn width(&self) -> usize {
+        self.content.width()
+    }
+
+    /// Patches the style of this Span, adding modifiers from the given style.
+    pub fn patch_style(&mut self, style: Style) {
+        self.style = self.style.patch(style);
+    }
+
+    /// Resets the style of the Span.
+    pub fn reset_style(&mut self) {
+        self.patch_style(Style::reset());
+    }
+
+    /// Returns an iterator over the graphemes held by this span.
+    pub fn styled_graphemes(
+        &self,
+        base_style: Style,
+    ) -> impl Iterator<Item = StyledGrapheme> + '_ {
+        let style = base_style.patch(self.style);
+        let link = self.link.clone();
+        self.content
+            .as_ref()
+            .graphemes(true)
+            .filter(|g| *g != "\n")
+            .map(move |g| StyledGrapheme::new(g, style).with_link(link.clone()))
+    }
+}
+
+impl<'a> From<&'a str> for Span<'a> {
+    fn from(s: &'a str) -> Span<'a> {
+        Span::raw(s)
+    }
+}
+
+impl<'a> From<String> for Span<'a> {
+    fn from(s: String) -> Span<'a> {
+        Span::raw(s)
+    }
+}
@@ -0,0 +1,37 @@
+use std::borrow::Cow;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::style::Style;
+
+/// A grapheme associated with a style.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct StyledGrapheme<'a> {
+    pub symbol: &'a str,
+    pub style: Style,
+    /// The link/URI this grapheme belongs to, if any. Carried forward from the originating
+    /// [`Span`](super::Span) so that a contiguous run of cells sharing the same link can be
+    /// grouped under a single OSC 8 hyperlink by the rendering backend.
+    pub link: Option<Cow<'a, str>>,
+}
+
+impl<'a> StyledGrapheme<'a> {
+    pub fn new(symbol: &'a str, style: Style) -> Self {
+        Self {
+            symbol,
+            style,
+            link: None,
+        }
+    }
+
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_link(mut self, link: Option<Cow<'a, str>>) -> Self {
+        self.link = link;
+        self
+    }
+
+    /// The display width of this grapheme's symbol.
+    pub fn width(&self) -> usize {
+        self.symbol.width()
+    }
+}
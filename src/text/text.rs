@@ -1,7 +1,8 @@
 use std::borrow::Cow;
+use std::str::Utf8Error;
 
-use super::{Line, Span};
-use crate::style::Style;
+use super::{ansi, Line, Span};
+use crate::{layout::Alignment, style::Style};
 
 /// A string split over multiple lines where each line is composed of several clusters, each with
 /// their own style.
@@ -36,6 +37,7 @@ use crate::style::Style;
 pub struct Text<'a> {
     pub lines: Vec<Line<'a>>,
     pub style: Style,
+    pub alignment: Option<Alignment>,
 }
 
 /// # Constructors
@@ -99,6 +101,30 @@ impl<'a> Text<'a> {
         raw.patch_style(style);
         raw.style(style)
     }
+
+    /// Parses `input` for ANSI/SGR escape sequences (`ESC [ ... m`), turning them into styled
+    /// [`Span`]s split into [`Line`]s on `\n`.
+    ///
+    /// Each SGR code mutates a running [`Style`] that subsequent spans inherit until the next code
+    /// changes it, so e.g. `"\x1b[1mbold\x1b[31mbold red"` produces a bold span followed by a bold
+    /// *and* red one, rather than resetting between them. Supports reset, the common text
+    /// modifiers (bold/dim/italic/underline/blink/reverse/hidden/crossed-out) and their
+    /// off-variants, the 16 named foreground/background colors, 256-color, and truecolor; any
+    /// other escape sequence (e.g. cursor movement) is dropped rather than rendered as garbage.
+    ///
+    /// This is useful for displaying captured program output (build logs, shell history) that
+    /// already contains color codes, without reaching for a separate crate to bridge it in.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let text = Text::from_ansi("\x1b[31mred\x1b[0m plain");
+    /// assert_eq!(text.lines[0].spans[0].style.fg, Some(Color::Red));
+    /// ```
+    pub fn from_ansi(input: &str) -> Text<'static> {
+        Text::from(ansi::parse(input))
+    }
 }
 
 /// # Builder methods
@@ -139,6 +165,57 @@ impl<'a> Text<'a> {
         self.style = style.into();
         self
     }
+
+    /// Sets the default alignment for this [`Text`].
+    ///
+    /// This applies only to lines that do not already have an explicit [`Line::alignment`] of
+    /// their own; see [`Text::styled_lines`]. Defaults to [`None`], which leaves alignment up to
+    /// the rendering widget.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let text = Text::from("The first line").alignment(Alignment::Center);
+    /// ```
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    /// Left-aligns this [`Text`]. Shorthand for `text.alignment(Alignment::Left)`.
+    pub fn left_aligned(self) -> Self {
+        self.alignment(Alignment::Left)
+    }
+
+    /// Centers this [`Text`]. Shorthand for `text.alignment(Alignment::Center)`.
+    pub fn centered(self) -> Self {
+        self.alignment(Alignment::Center)
+    }
+
+    /// Right-aligns this [`Text`]. Shorthand for `text.alignment(Alignment::Right)`.
+    pub fn right_aligned(self) -> Self {
+        self.alignment(Alignment::Right)
+    }
+
+    /// Reflows the text to fit within `max_width` columns, preserving each span's style.
+    ///
+    /// Existing `\n` boundaries (already captured as separate [`Line`]s) remain forced breaks;
+    /// each line is independently word-wrapped via [`Line::wrapped`]. This lets callers measure
+    /// and cache wrapped text outside of a render pass, rather than relying on `Paragraph`'s own
+    /// wrapping.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let text = Text::from("The quick brown fox").wrapped(10);
+    /// assert_eq!(text.height(), 2);
+    /// ```
+    pub fn wrapped(mut self, max_width: u16) -> Self {
+        self.wrap_to_width(max_width);
+        self
+    }
 }
 
 impl Text<'_> {
@@ -185,6 +262,54 @@ impl Text<'_> {
         }
     }
 
+    /// Reflows the text in place to fit within `max_width` columns. See [`Text::wrapped`] for the
+    /// consuming, chainable version of this method.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let mut text = Text::from("The quick brown fox");
+    /// text.wrap_to_width(10);
+    /// assert_eq!(text.height(), 2);
+    /// ```
+    pub fn wrap_to_width(&mut self, max_width: u16) {
+        self.lines = self
+            .lines
+            .iter()
+            .flat_map(|line| line.wrapped(max_width as usize, true))
+            .collect();
+    }
+
+    /// Clips each line to `max_width` cells, appending `ellipsis` where a line was cut, instead of
+    /// wrapping it onto further lines. See [`Line::truncated`] for the grapheme-aware truncation
+    /// this delegates to.
+    ///
+    /// Each line is truncated from the end implied by its own [`Line::alignment`] (falling back to
+    /// [`Text::alignment`], then [`Alignment::Left`] if neither is set), so content already
+    /// clipped off-screen by a right- or center-aligned line is dropped from the matching end.
+    /// Lines already within budget are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let text = Text::from("Hello, world!").truncated(8, "...");
+    /// assert_eq!(String::from(text.lines[0].clone()), "Hello...");
+    /// ```
+    pub fn truncated(mut self, max_width: u16, ellipsis: &str) -> Self {
+        let default_alignment = self.alignment.unwrap_or(Alignment::Left);
+        self.lines = self
+            .lines
+            .iter()
+            .map(|line| {
+                let align = line.alignment.unwrap_or(default_alignment);
+                line.truncated(max_width as usize, ellipsis, align)
+            })
+            .collect();
+        self
+    }
+
     /// Returns the max width of all the lines.
     ///
     /// # Examples
@@ -213,7 +338,9 @@ impl Text<'_> {
 
     /// Returns an iterator over the lines of the text.
     ///
-    /// Each line is styled with the style of the text.
+    /// Each line is styled with the style of the text (replacing the line's own style), and any
+    /// line that does not already carry its own [`Line::alignment`] inherits the text's
+    /// [`Text::alignment`] instead.
     ///
     /// # Examples
     ///
@@ -224,10 +351,11 @@ impl Text<'_> {
     /// let styled_lines = text.styled_lines().collect::<Vec<_>>();
     /// ````
     pub fn styled_lines(&self) -> impl Iterator<Item = Line> {
-        self.lines
-            .iter()
-            .cloned()
-            .map(|line| line.style(self.style))
+        self.lines.iter().cloned().map(|line| {
+            let mut line = line.style(self.style);
+            line.alignment = line.alignment.or(self.alignment);
+            line
+        })
     }
 }
 
@@ -276,6 +404,15 @@ impl<'a> From<Vec<Line<'a>>> for Text<'a> {
     }
 }
 
+impl<'a> TryFrom<&[u8]> for Text<'a> {
+    type Error = Utf8Error;
+
+    /// Interprets `bytes` as UTF-8 and parses it for ANSI escape sequences via [`Text::from_ansi`].
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Text::from_ansi(std::str::from_utf8(bytes)?))
+    }
+}
+
 impl<'a> IntoIterator for Text<'a> {
     type Item = Line<'a>;
     type IntoIter = std::vec::IntoIter<Self::Item>;
@@ -300,6 +437,17 @@ mod tests {
     use super::*;
     use crate::style::Stylize;
 
+    #[test]
+    fn styled_lines_inherits_text_alignment() {
+        let text = Text::from(vec![
+            Line::from("Title").alignment(Alignment::Center),
+            Line::from("Body"),
+        ])
+        .left_aligned();
+        let alignments: Vec<_> = text.styled_lines().map(|line| line.alignment).collect();
+        assert_eq!(alignments, vec![Some(Alignment::Center), Some(Alignment::Left)]);
+    }
+
     #[test]
     fn raw() {
         let text = Text::raw("The first line\nThe second line");
@@ -469,6 +617,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn wrapped() {
+        let text = Text::from("The quick brown fox").wrapped(10);
+        assert_eq!(
+            text.lines,
+            vec![Line::from("The quick "), Line::from("brown fox")]
+        );
+    }
+
+    #[test]
+    fn wrap_to_width_preserves_forced_line_breaks() {
+        let mut text = Text::from("short\nThe quick brown fox");
+        text.wrap_to_width(10);
+        assert_eq!(
+            text.lines,
+            vec![
+                Line::from("short"),
+                Line::from("The quick "),
+                Line::from("brown fox"),
+            ]
+        );
+    }
+
+    #[test]
+    fn truncated() {
+        let text = Text::from("Hello, world!").truncated(8, "...");
+        assert_eq!(
+            text.lines,
+            vec![Line::from(vec![Span::raw("Hello"), Span::raw("...")])]
+        );
+    }
+
+    #[test]
+    fn truncated_honors_per_line_alignment() {
+        let text = Text::from(vec![
+            Line::from("Hello, world!").alignment(Alignment::Right),
+            Line::from("Hello, world!"),
+        ])
+        .truncated(8, "...");
+        let rendered: Vec<String> = text.lines.iter().map(|l| String::from(l.clone())).collect();
+        assert_eq!(rendered, vec!["...orld!", "Hello..."]);
+    }
+
+    #[test]
+    fn from_ansi() {
+        let text = Text::from_ansi("\x1b[31mred\x1b[0m plain");
+        assert_eq!(
+            text.lines,
+            vec![Line::from(vec![
+                Span::styled("red", Style::new().red()),
+                Span::raw(" plain"),
+            ])]
+        );
+    }
+
+    #[test]
+    fn try_from_bytes() {
+        let text = Text::try_from(b"plain".as_slice()).unwrap();
+        assert_eq!(text.lines, vec![Line::from("plain")]);
+    }
+
     #[test]
     fn extend_from_iter_str() {
         let mut text = Text::from("The first line\nThe second line");
@@ -0,0 +1,435 @@
+use crate::{
+    prelude::*,
+    widgets::{Block, StatefulWidget, Widget},
+};
+
+/// A single bar in a [`BarChart`].
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Bar<'a> {
+    /// Value to display on the bar.
+    pub value: u64,
+    /// Optional label to be printed under the bar.
+    pub label: Option<Line<'a>>,
+    /// Style of the bar.
+    pub style: Style,
+    /// Style of the value printed at the bottom of the bar.
+    pub value_style: Style,
+}
+
+impl<'a> Bar<'a> {
+    pub fn value(mut self, value: u64) -> Bar<'a> {
+        self.value = value;
+        self
+    }
+
+    pub fn label(mut self, label: Line<'a>) -> Bar<'a> {
+        self.label = Some(label);
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> Bar<'a> {
+        self.style = style;
+        self
+    }
+
+    pub fn value_style(mut self, style: Style) -> Bar<'a> {
+        self.value_style = style;
+        self
+    }
+}
+
+impl<'a> From<u64> for Bar<'a> {
+    fn from(value: u64) -> Self {
+        Bar::default().value(value)
+    }
+}
+
+/// Direction in which the bars of a [`BarChart`] are drawn.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Direction {
+    /// Bars grow upwards from the bottom of the area.
+    #[default]
+    Vertical,
+    /// Bars grow rightwards from the left of the area.
+    Horizontal,
+}
+
+/// A chart showing a list of values as bars.
+///
+/// See [`BarChartState`] for rendering only a window of the bars, with the last drawn offset
+/// remembered between frames the same way [`crate::widgets::ListState`] does for [`List`].
+///
+/// [`List`]: crate::widgets::List
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct BarChart<'a> {
+    block: Option<Block<'a>>,
+    bars: Vec<Bar<'a>>,
+    bar_width: u16,
+    bar_gap: u16,
+    direction: Direction,
+    style: Style,
+    highlight_style: Style,
+    max: Option<u64>,
+}
+
+impl<'a> BarChart<'a> {
+    pub fn new(bars: Vec<Bar<'a>>) -> Self {
+        Self {
+            bars,
+            bar_width: 1,
+            bar_gap: 1,
+            ..Default::default()
+        }
+    }
+
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn bar_width(mut self, width: u16) -> Self {
+        self.bar_width = width;
+        self
+    }
+
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn bar_gap(mut self, gap: u16) -> Self {
+        self.bar_gap = gap;
+        self
+    }
+
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style used to draw the selected bar when rendered with a [`BarChartState`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn max(mut self, max: u64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    fn inner_area(&self, area: Rect, buf: &mut Buffer) -> Rect {
+        match &self.block {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.clone().render(area, buf);
+                inner
+            }
+            None => area,
+        }
+    }
+
+    /// Number of cells a single bar (plus its trailing gap) occupies along the growth axis.
+    fn bar_stride(&self) -> u16 {
+        self.bar_width + self.bar_gap
+    }
+
+    /// Number of whole bars that fit in `area` given the current `bar_width`/`bar_gap`.
+    fn visible_bar_count(&self, area: Rect) -> usize {
+        let extent = match self.direction {
+            Direction::Vertical => area.width,
+            Direction::Horizontal => area.height,
+        };
+        let stride = self.bar_stride().max(1);
+        (extent / stride) as usize
+    }
+
+    fn max_value(&self, bars: &[Bar]) -> u64 {
+        self.max
+            .unwrap_or_else(|| bars.iter().map(|b| b.value).max().unwrap_or_default())
+    }
+
+    /// Renders `bars` into `area`, reserving a trailing row for labels first if any bar (in
+    /// [`Direction::Vertical`]) has one, since labels sit under the whole chart rather than
+    /// alongside any single bar.
+    fn render_bars(&self, area: Rect, buf: &mut Buffer, bars: &[Bar], selected: Option<usize>) {
+        let max = self.max_value(bars).max(1);
+        let stride = self.bar_stride();
+
+        let (chart_area, label_row) =
+            if self.direction == Direction::Vertical && bars.iter().any(|b| b.label.is_some()) {
+                let chart_area = Rect {
+                    height: area.height.saturating_sub(1),
+                    ..area
+                };
+                (chart_area, Some(area.bottom().saturating_sub(1)))
+            } else {
+                (area, None)
+            };
+
+        for (i, bar) in bars.iter().enumerate() {
+            let style = if selected == Some(i) {
+                self.highlight_style
+            } else {
+                bar.style
+            };
+            let offset = i as u16 * stride;
+
+            match self.direction {
+                Direction::Vertical => {
+                    let bar_width = self.bar_width.min(chart_area.width.saturating_sub(offset));
+                    if bar_width == 0 {
+                        continue;
+                    }
+                    let height = (u64::from(chart_area.height) * bar.value / max)
+                        .min(u64::from(chart_area.height)) as u16;
+                    let bar_area = Rect {
+                        x: chart_area.x + offset,
+                        y: chart_area.bottom().saturating_sub(height),
+                        width: bar_width,
+                        height,
+                    };
+                    buf.set_style(bar_area, style);
+
+                    // The value sits on the bar's bottommost row (the one closest to the axis),
+                    // which is always filled as long as the bar is visible at all.
+                    if height > 0 {
+                        buf.set_stringn(
+                            bar_area.x,
+                            bar_area.bottom() - 1,
+                            bar.value.to_string(),
+                            bar_width as usize,
+                            style.patch(bar.value_style),
+                        );
+                    }
+
+                    if let (Some(label_row), Some(label)) = (label_row, &bar.label) {
+                        buf.set_line(bar_area.x, label_row, label, bar_width);
+                    }
+                }
+                Direction::Horizontal => {
+                    let row_height = self.bar_width.min(chart_area.height.saturating_sub(offset));
+                    if row_height == 0 {
+                        continue;
+                    }
+                    let width = (u64::from(chart_area.width) * bar.value / max)
+                        .min(u64::from(chart_area.width)) as u16;
+                    let bar_area = Rect {
+                        x: chart_area.x,
+                        y: chart_area.y + offset,
+                        width,
+                        height: row_height,
+                    };
+                    buf.set_style(bar_area, style);
+
+                    if width > 0 {
+                        buf.set_stringn(
+                            bar_area.x,
+                            bar_area.y,
+                            bar.value.to_string(),
+                            width as usize,
+                            style.patch(bar.value_style),
+                        );
+                    }
+
+                    if let Some(label) = &bar.label {
+                        let label_x = chart_area.x + width;
+                        let label_width = chart_area.width.saturating_sub(width);
+                        if label_width > 0 {
+                            buf.set_line(label_x, bar_area.y, label, label_width);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Widget for BarChart<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        let inner = self.inner_area(area, buf);
+        let bars = self.bars.clone();
+        self.render_bars(inner, buf, &bars, None);
+    }
+}
+
+/// State of a [`BarChart`] rendered as a [`StatefulWidget`], tracking how far it has scrolled.
+///
+/// Mirrors the "remember the last offset between draws" pattern used by
+/// [`ListState`](crate::widgets::ListState): when [`BarChartState::selected`] falls outside the
+/// bars currently visible, [`BarChart`] nudges [`BarChartState::offset`] just enough to bring it
+/// back into view; otherwise the stored offset is reused as-is.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct BarChartState {
+    pub offset: usize,
+    pub selected: Option<usize>,
+}
+
+impl BarChartState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selected = index;
+    }
+}
+
+impl<'a> StatefulWidget for BarChart<'a> {
+    type State = BarChartState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        let inner = self.inner_area(area, buf);
+        let visible = self.visible_bar_count(inner).max(1);
+
+        if let Some(selected) = state.selected {
+            if selected < state.offset {
+                state.offset = selected;
+            } else if selected >= state.offset + visible {
+                state.offset = selected + 1 - visible;
+            }
+        }
+        state.offset = state.offset.min(self.bars.len().saturating_sub(1));
+
+        let window = &self.bars[state.offset..self.bars.len().min(state.offset + visible)];
+        let selected_in_window = state.selected.and_then(|s| s.checked_sub(state.offset));
+        self.render_bars(inner, buf, window, selected_in_window);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Color;
+
+    #[test]
+    fn renders_a_value_on_each_bar() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 3));
+        let bars = vec![
+            Bar::from(3).value_style(Style::new().fg(Color::Red)),
+            Bar::from(1).value_style(Style::new().fg(Color::Blue)),
+        ];
+        BarChart::new(bars).render(buffer.area, &mut buffer);
+
+        // Bar 0 (3 rows tall) occupies column 0 with its value on the bottom row; bar 1 (1 row
+        // tall) occupies column 2, only tall enough for its value row.
+        assert_eq!(buffer.get(0, 2).symbol, "3");
+        assert_eq!(buffer.get(0, 2).style.fg, Some(Color::Red));
+        assert_eq!(buffer.get(2, 2).symbol, "1");
+        assert_eq!(buffer.get(2, 2).style.fg, Some(Color::Blue));
+        assert_eq!(buffer.get(2, 1).symbol, " ");
+    }
+
+    #[test]
+    fn renders_a_label_under_each_bar_and_reserves_its_row() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 3));
+        let bars = vec![
+            Bar::from(2).label(Line::from("a")),
+            Bar::from(2).label(Line::from("b")),
+        ];
+        BarChart::new(bars).render(buffer.area, &mut buffer);
+
+        // The label row is reserved, so a bar with the chart's max value only fills the 2 rows
+        // left above it, not all 3.
+        assert_eq!(buffer.get(0, 0).symbol, " ");
+        assert_eq!(buffer.get(0, 2).symbol, "a");
+        assert_eq!(buffer.get(2, 2).symbol, "b");
+    }
+
+    #[test]
+    fn no_label_row_is_reserved_when_no_bar_has_a_label() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 3));
+        let bar = Bar::from(3).style(Style::new().bg(Color::Red));
+        BarChart::new(vec![bar]).render(buffer.area, &mut buffer);
+
+        // With max == value and no label reserved, the bar fills every one of the 3 rows,
+        // including the top one.
+        assert_eq!(buffer.get(0, 0).style.bg, Some(Color::Red));
+    }
+
+    #[test]
+    fn a_label_reserves_a_row_that_shrinks_the_bar() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 3));
+        let bar = Bar::from(3)
+            .style(Style::new().bg(Color::Red))
+            .label(Line::from("x"));
+        BarChart::new(vec![bar]).render(buffer.area, &mut buffer);
+
+        // The bottom row is now the label row, so the bar (still scaled to the chart's own max)
+        // fills the remaining 2 rows, with its value on row 1 just above the label.
+        assert_eq!(buffer.get(0, 0).style.bg, Some(Color::Red));
+        assert_eq!(buffer.get(0, 1).symbol, "3");
+        assert_eq!(buffer.get(0, 2).symbol, "x");
+    }
+
+    #[test]
+    fn selecting_a_bar_past_the_visible_window_scrolls_forward() {
+        let bars: Vec<Bar> = (0..5).map(Bar::from).collect();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        let mut state = BarChartState::new();
+        state.select(Some(4));
+        BarChart::new(bars)
+            .bar_width(1)
+            .bar_gap(0)
+            .render(buffer.area, &mut buffer, &mut state);
+
+        // Only 3 bars fit; selecting index 4 must scroll so it's the last one visible.
+        assert_eq!(state.offset, 2);
+    }
+
+    #[test]
+    fn selecting_a_bar_before_the_visible_window_scrolls_backward() {
+        let bars: Vec<Bar> = (0..5).map(Bar::from).collect();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        let mut state = BarChartState::new().with_offset(3);
+        state.select(Some(1));
+        BarChart::new(bars)
+            .bar_width(1)
+            .bar_gap(0)
+            .render(buffer.area, &mut buffer, &mut state);
+
+        assert_eq!(state.offset, 1);
+    }
+
+    #[test]
+    fn offset_is_clamped_to_the_last_bar() {
+        let bars: Vec<Bar> = (0..3).map(Bar::from).collect();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        let mut state = BarChartState::new().with_offset(100);
+        BarChart::new(bars)
+            .bar_width(1)
+            .bar_gap(0)
+            .render(buffer.area, &mut buffer, &mut state);
+
+        assert_eq!(state.offset, 2);
+    }
+
+    #[test]
+    fn offset_within_the_visible_window_is_left_untouched() {
+        let bars: Vec<Bar> = (0..5).map(Bar::from).collect();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        let mut state = BarChartState::new().with_offset(1);
+        state.select(Some(2));
+        BarChart::new(bars)
+            .bar_width(1)
+            .bar_gap(0)
+            .render(buffer.area, &mut buffer, &mut state);
+
+        assert_eq!(state.offset, 1);
+    }
+}
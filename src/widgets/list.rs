@@ -0,0 +1,341 @@
+use crate::{
+    prelude::*,
+    widgets::{Block, StatefulWidget, Widget},
+};
+
+/// A single cell of a multi-column [`ListItem`], with its own content and layout [`Constraint`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ListColumn<'a> {
+    content: Text<'a>,
+    constraint: Constraint,
+}
+
+impl<'a> ListColumn<'a> {
+    pub fn new<T: Into<Text<'a>>>(content: T, constraint: Constraint) -> Self {
+        Self {
+            content: content.into(),
+            constraint,
+        }
+    }
+}
+
+/// Content of a [`ListItem`]: either a single block of text (the common case) or several
+/// independently-styled, independently-sized columns.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+enum ListItemContent<'a> {
+    Single(Text<'a>),
+    Columns(Vec<ListColumn<'a>>),
+}
+
+/// An item to be displayed by a [`List`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ListItem<'a> {
+    content: ListItemContent<'a>,
+    style: Style,
+}
+
+impl<'a> ListItem<'a> {
+    pub fn new<T: Into<Text<'a>>>(content: T) -> Self {
+        Self {
+            content: ListItemContent::Single(content.into()),
+            style: Style::default(),
+        }
+    }
+
+    /// Creates a multi-column item, e.g. an inbox row with an aligned `from` and `subject`
+    /// column, without the caller having to measure widths and pad strings by hand.
+    ///
+    /// Each column keeps its own [`Text`] (and thus its own styling) and is laid out by `List`
+    /// according to its [`Constraint`] the same way [`Layout`] lays out any other segment,
+    /// after reserving space for the highlight symbol.
+    pub fn columns(columns: Vec<ListColumn<'a>>) -> Self {
+        Self {
+            content: ListItemContent::Columns(columns),
+            style: Style::default(),
+        }
+    }
+
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn height(&self) -> usize {
+        match &self.content {
+            ListItemContent::Single(text) => text.height(),
+            ListItemContent::Columns(columns) => {
+                columns.iter().map(|c| c.content.height()).max().unwrap_or(1)
+            }
+        }
+    }
+}
+
+/// A widget to display several items among which one can be selected.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct List<'a> {
+    block: Option<Block<'a>>,
+    items: Vec<ListItem<'a>>,
+    style: Style,
+    highlight_style: Style,
+    highlight_symbol: Option<&'a str>,
+}
+
+impl<'a> Default for List<'a> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            items: Vec::new(),
+            style: Style::default(),
+            highlight_style: Style::default(),
+            highlight_symbol: None,
+        }
+    }
+}
+
+impl<'a> List<'a> {
+    pub fn new<T>(items: T) -> Self
+    where
+        T: Into<Vec<ListItem<'a>>>,
+    {
+        Self {
+            items: items.into(),
+            ..Default::default()
+        }
+    }
+
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_style = style.into();
+        self
+    }
+
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_symbol(mut self, symbol: &'a str) -> Self {
+        self.highlight_symbol = Some(symbol);
+        self
+    }
+
+    fn inner_area(&self, area: Rect, buf: &mut Buffer) -> Rect {
+        match &self.block {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.clone().render(area, buf);
+                inner
+            }
+            None => area,
+        }
+    }
+
+    fn render_item(&self, item: &ListItem, area: Rect, buf: &mut Buffer, selected: bool) {
+        let style = if selected {
+            self.highlight_style
+        } else {
+            item.style
+        };
+        buf.set_style(area, style);
+
+        let symbol_width = self.highlight_symbol.map(|s| s.width() as u16).unwrap_or(0);
+        if let Some(symbol) = self.highlight_symbol {
+            if selected {
+                buf.set_stringn(area.x, area.y, symbol, symbol_width as usize, style);
+            }
+        }
+        let content_area = Rect {
+            x: area.x + symbol_width,
+            width: area.width.saturating_sub(symbol_width),
+            ..area
+        };
+
+        match &item.content {
+            ListItemContent::Single(text) => {
+                for (i, line) in text.lines.iter().enumerate().take(content_area.height as usize) {
+                    buf.set_line(content_area.x, content_area.y + i as u16, line, content_area.width);
+                }
+            }
+            ListItemContent::Columns(columns) => {
+                let constraints: Vec<Constraint> = columns.iter().map(|c| c.constraint).collect();
+                let column_areas = Layout::horizontal(&constraints).split(content_area);
+                for (column, column_area) in columns.iter().zip(column_areas.iter()) {
+                    for (i, line) in column
+                        .content
+                        .lines
+                        .iter()
+                        .enumerate()
+                        .take(column_area.height as usize)
+                    {
+                        buf.set_line(column_area.x, column_area.y + i as u16, line, column_area.width);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Widget for List<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        let inner = self.inner_area(area, buf);
+        let mut y = inner.y;
+        for item in &self.items {
+            let height = item.height() as u16;
+            let item_area = Rect {
+                y,
+                height: height.min(inner.bottom().saturating_sub(y)),
+                ..inner
+            };
+            if item_area.height == 0 {
+                break;
+            }
+            self.render_item(item, item_area, buf, false);
+            y += height;
+        }
+    }
+}
+
+/// State of a [`List`], tracking the selected item and the scroll offset.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ListState {
+    pub offset: usize,
+    pub selected: Option<usize>,
+}
+
+impl ListState {
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selected = index;
+    }
+}
+
+impl<'a> StatefulWidget for List<'a> {
+    type State = ListState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        let inner = self.inner_area(area, buf);
+        let mut y = inner.y;
+        for (index, item) in self.items.iter().enumerate().skip(state.offset) {
+            let height = item.height() as u16;
+            if y >= inner.bottom() {
+                break;
+            }
+            let item_area = Rect {
+                y,
+                height: height.min(inner.bottom().saturating_sub(y)),
+                ..inner
+            };
+            self.render_item(item, item_area, buf, state.selected == Some(index));
+            y += height;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Color;
+
+    #[test]
+    fn renders_each_item_on_its_own_row() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 2));
+        let list = List::new(vec![ListItem::new("one"), ListItem::new("two")]);
+        list.render(buffer.area, &mut buffer);
+
+        assert_eq!(buffer.get(0, 0).symbol, "o");
+        assert_eq!(buffer.get(1, 0).symbol, "n");
+        assert_eq!(buffer.get(0, 1).symbol, "t");
+        assert_eq!(buffer.get(1, 1).symbol, "w");
+    }
+
+    #[test]
+    fn items_past_the_visible_area_are_not_rendered() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        let list = List::new(vec![ListItem::new("one"), ListItem::new("two")]);
+        list.render(buffer.area, &mut buffer);
+
+        assert_eq!(buffer.get(0, 0).symbol, "o");
+    }
+
+    #[test]
+    fn highlight_symbol_reserves_a_column_and_only_shows_on_the_selected_row() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 2));
+        let mut state = ListState::default();
+        state.select(Some(1));
+        List::new(vec![ListItem::new("one"), ListItem::new("two")])
+            .highlight_symbol(">")
+            .render(buffer.area, &mut buffer, &mut state);
+
+        assert_eq!(buffer.get(0, 0).symbol, " ");
+        assert_eq!(buffer.get(1, 0).symbol, "o");
+        assert_eq!(buffer.get(0, 1).symbol, ">");
+        assert_eq!(buffer.get(1, 1).symbol, "t");
+    }
+
+    #[test]
+    fn multi_column_items_lay_out_columns_side_by_side() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let item = ListItem::columns(vec![
+            ListColumn::new("from", Constraint::Length(4)),
+            ListColumn::new("subject", Constraint::Length(6)),
+        ]);
+        List::new(vec![item]).render(buffer.area, &mut buffer);
+
+        assert_eq!(buffer.get(0, 0).symbol, "f");
+        assert_eq!(buffer.get(4, 0).symbol, "s");
+    }
+
+    #[test]
+    fn highlight_symbol_width_is_reserved_before_the_columns() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let mut state = ListState::default();
+        state.select(Some(0));
+        let item = ListItem::columns(vec![
+            ListColumn::new("from", Constraint::Length(4)),
+            ListColumn::new("subject", Constraint::Length(5)),
+        ]);
+        List::new(vec![item])
+            .highlight_symbol(">>")
+            .render(buffer.area, &mut buffer, &mut state);
+
+        assert_eq!(buffer.get(0, 0).symbol, ">");
+        assert_eq!(buffer.get(1, 0).symbol, ">");
+        assert_eq!(buffer.get(2, 0).symbol, "f");
+    }
+
+    #[test]
+    fn offset_skips_items_before_it() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        let mut state = ListState {
+            offset: 1,
+            selected: None,
+        };
+        List::new(vec![ListItem::new("one"), ListItem::new("two")])
+            .render(buffer.area, &mut buffer, &mut state);
+
+        assert_eq!(buffer.get(0, 0).symbol, "t");
+    }
+
+    #[test]
+    fn selected_row_is_highlighted() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 2));
+        let mut state = ListState::default();
+        state.select(Some(1));
+        List::new(vec![ListItem::new("one"), ListItem::new("two")])
+            .highlight_style(Style::new().bg(Color::Yellow))
+            .render(buffer.area, &mut buffer, &mut state);
+
+        assert_eq!(buffer.get(0, 0).style.bg, None);
+        assert_eq!(buffer.get(0, 1).style.bg, Some(Color::Yellow));
+    }
+}
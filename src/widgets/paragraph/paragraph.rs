@@ -0,0 +1,111 @@
+use crate::{
+    prelude::*,
+    widgets::{Block, Widget},
+};
+
+/// Describes how [`Paragraph`] should wrap text that is too long for the available width.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Wrap {
+    /// Trim leading whitespace on wrapped lines (other than the first line of a paragraph line).
+    pub trim: bool,
+}
+
+/// A widget to display some text, optionally wrapped and scrolled.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Paragraph<'a> {
+    block: Option<Block<'a>>,
+    style: Style,
+    text: Text<'a>,
+    wrap: Option<Wrap>,
+    scroll: (u16, u16),
+    alignment: Alignment,
+}
+
+impl<'a> Paragraph<'a> {
+    pub fn new<T: Into<Text<'a>>>(text: T) -> Self {
+        Self {
+            block: None,
+            style: Style::default(),
+            text: text.into(),
+            wrap: None,
+            scroll: (0, 0),
+            alignment: Alignment::Left,
+        }
+    }
+
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn wrap(mut self, wrap: Wrap) -> Self {
+        self.wrap = Some(wrap);
+        self
+    }
+
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn scroll(mut self, offset: (u16, u16)) -> Self {
+        self.scroll = offset;
+        self
+    }
+
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    pub fn text(&self) -> &Text<'a> {
+        &self.text
+    }
+
+    /// The lines this paragraph will actually draw at `width`, after the wrap (if any) is
+    /// applied.
+    ///
+    /// Exposed so [`super::search::ParagraphSearch`] can scan the same visual lines the paragraph
+    /// renders, including wrap boundaries, instead of re-implementing the wrap itself.
+    pub(crate) fn wrapped_lines(&self, width: u16) -> Vec<Line<'a>> {
+        match self.wrap {
+            Some(Wrap { trim }) => self
+                .text
+                .lines
+                .iter()
+                .flat_map(|line| line.wrapped(width as usize, trim))
+                .collect(),
+            None => self.text.lines.clone(),
+        }
+    }
+}
+
+impl<'a> Widget for Paragraph<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        let inner = match &self.block {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.clone().render(area, buf);
+                inner
+            }
+            None => area,
+        };
+
+        let lines = self.wrapped_lines(inner.width);
+        for (i, line) in lines
+            .iter()
+            .skip(self.scroll.0 as usize)
+            .take(inner.height as usize)
+            .enumerate()
+        {
+            let line = line.skip_columns(self.scroll.1 as usize);
+            buf.set_line(inner.x, inner.y + i as u16, &line, inner.width);
+        }
+    }
+}
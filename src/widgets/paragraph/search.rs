@@ -0,0 +1,244 @@
+use regex::Regex;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::{style::Style, text::Line};
+
+use super::Paragraph;
+
+/// A single match found by [`ParagraphSearch`], in the column space of one wrapped visual line.
+///
+/// `line` indexes into the wrapped lines a [`Paragraph`] actually renders (i.e. it already
+/// accounts for wrap boundaries). Each visual line is searched on its own, so text that a wrap
+/// boundary splits across two visual lines is not matched across that boundary.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Match {
+    pub line: usize,
+    pub start_col: usize,
+    pub end_col: usize,
+}
+
+/// Finds and highlights occurrences of a search query within a [`Paragraph`]'s wrapped lines.
+///
+/// Matching is done on the same grapheme-wrapped visual lines the paragraph renders, so results
+/// line up with what's on screen even when the text has been word-wrapped. Columns are grapheme
+/// columns (via `unicode-width`), not byte offsets, so multi-width characters don't throw off
+/// alignment.
+#[derive(Debug, Clone)]
+pub struct ParagraphSearch {
+    matches: Vec<Match>,
+    current: Option<usize>,
+}
+
+impl ParagraphSearch {
+    /// Searches `paragraph`'s lines as wrapped at `width` for `pattern`, treated as a regex (a
+    /// plain literal query is also a valid regex).
+    pub fn new(paragraph: &Paragraph, width: u16, pattern: &str) -> Result<Self, regex::Error> {
+        let regex = Regex::new(pattern)?;
+        let mut matches = Vec::new();
+        for (line_index, line) in paragraph.wrapped_lines(width).iter().enumerate() {
+            let text: String = line.spans.iter().map(|span| span.content.as_ref()).collect();
+            for found in regex.find_iter(&text) {
+                matches.push(Match {
+                    line: line_index,
+                    start_col: byte_to_column(&text, found.start()),
+                    end_col: byte_to_column(&text, found.end()),
+                });
+            }
+        }
+        Ok(Self {
+            matches,
+            current: None,
+        })
+    }
+
+    pub fn matches(&self) -> &[Match] {
+        &self.matches
+    }
+
+    /// Advances to the next match (wrapping around to the first), returning the scroll offset
+    /// `(line, 0)` needed to bring it into view.
+    pub fn next_match(&mut self) -> Option<(u16, u16)> {
+        if self.matches.is_empty() {
+            return None;
+        }
+        let next = match self.current {
+            Some(index) => (index + 1) % self.matches.len(),
+            None => 0,
+        };
+        self.current = Some(next);
+        Some(self.scroll_offset(next))
+    }
+
+    /// Moves to the previous match (wrapping around to the last), returning its scroll offset.
+    pub fn prev_match(&mut self) -> Option<(u16, u16)> {
+        if self.matches.is_empty() {
+            return None;
+        }
+        let prev = match self.current {
+            Some(0) | None => self.matches.len() - 1,
+            Some(index) => index - 1,
+        };
+        self.current = Some(prev);
+        Some(self.scroll_offset(prev))
+    }
+
+    fn scroll_offset(&self, index: usize) -> (u16, u16) {
+        (self.matches[index].line as u16, 0)
+    }
+
+    /// Returns `lines` with `style` patched onto every cell covered by a match, so callers can
+    /// render the highlighted result in place of the paragraph's original wrapped lines.
+    pub fn highlight<'a>(&self, lines: &[Line<'a>], style: Style) -> Vec<Line<'a>> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(line_index, line)| {
+                let line_matches: Vec<&Match> =
+                    self.matches.iter().filter(|m| m.line == line_index).collect();
+                if line_matches.is_empty() {
+                    return line.clone();
+                }
+
+                let mut column = 0usize;
+                let spans: Vec<_> = line
+                    .styled_graphemes(line.style)
+                    .map(|grapheme| {
+                        let start = column;
+                        column += grapheme.symbol.width();
+                        let matched = line_matches
+                            .iter()
+                            .any(|m| start >= m.start_col && start < m.end_col);
+                        let grapheme_style = if matched {
+                            grapheme.style.patch(style)
+                        } else {
+                            grapheme.style
+                        };
+                        crate::text::Span::styled(grapheme.symbol.to_owned(), grapheme_style)
+                    })
+                    .collect();
+                let mut highlighted = Line::from(spans).style(line.style);
+                if let Some(alignment) = line.alignment {
+                    highlighted = highlighted.alignment(alignment);
+                }
+                highlighted
+            })
+            .collect()
+    }
+}
+
+/// Converts a byte offset within `text` to a grapheme column, summing the display width of every
+/// grapheme before it.
+fn byte_to_column(text: &str, byte_offset: usize) -> usize {
+    text.grapheme_indices(true)
+        .take_while(|(index, _)| *index < byte_offset)
+        .map(|(_, grapheme)| grapheme.width())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Color;
+
+    #[test]
+    fn finds_a_plain_literal_match() {
+        let paragraph = Paragraph::new("hello world");
+        let search = ParagraphSearch::new(&paragraph, 80, "world").unwrap();
+        assert_eq!(
+            search.matches(),
+            &[Match {
+                line: 0,
+                start_col: 6,
+                end_col: 11
+            }]
+        );
+    }
+
+    #[test]
+    fn finds_matches_split_across_wrapped_lines() {
+        // At width 7, "foo foo foo" wraps to "foo foo" / " foo" (the wrap keeps the leading space
+        // on the second line since `trim: false`), so the third "foo" is reported on line 1.
+        let paragraph = Paragraph::new("foo foo foo").wrap(crate::widgets::Wrap { trim: false });
+        let search = ParagraphSearch::new(&paragraph, 7, "foo").unwrap();
+        assert_eq!(
+            search.matches(),
+            &[
+                Match { line: 0, start_col: 0, end_col: 3 },
+                Match { line: 0, start_col: 4, end_col: 7 },
+                Match { line: 1, start_col: 1, end_col: 4 },
+            ]
+        );
+    }
+
+    #[test]
+    fn no_matches_when_pattern_is_absent() {
+        let paragraph = Paragraph::new("hello world");
+        let search = ParagraphSearch::new(&paragraph, 80, "xyz").unwrap();
+        assert!(search.matches().is_empty());
+    }
+
+    #[test]
+    fn invalid_regex_is_an_error() {
+        let paragraph = Paragraph::new("hello");
+        assert!(ParagraphSearch::new(&paragraph, 80, "[").is_err());
+    }
+
+    #[test]
+    fn next_match_wraps_around() {
+        let paragraph = Paragraph::new("foo\nfoo\nfoo");
+        let mut search = ParagraphSearch::new(&paragraph, 80, "foo").unwrap();
+        assert_eq!(search.next_match(), Some((0, 0)));
+        assert_eq!(search.next_match(), Some((1, 0)));
+        assert_eq!(search.next_match(), Some((2, 0)));
+        assert_eq!(search.next_match(), Some((0, 0)));
+    }
+
+    #[test]
+    fn prev_match_wraps_around_from_the_start() {
+        let paragraph = Paragraph::new("foo\nfoo");
+        let mut search = ParagraphSearch::new(&paragraph, 80, "foo").unwrap();
+        assert_eq!(search.prev_match(), Some((1, 0)));
+        assert_eq!(search.prev_match(), Some((0, 0)));
+    }
+
+    #[test]
+    fn no_matches_navigation_returns_none() {
+        let paragraph = Paragraph::new("hello");
+        let mut search = ParagraphSearch::new(&paragraph, 80, "xyz").unwrap();
+        assert_eq!(search.next_match(), None);
+        assert_eq!(search.prev_match(), None);
+    }
+
+    #[test]
+    fn highlight_patches_style_only_on_matched_columns() {
+        let paragraph = Paragraph::new("hello world");
+        let search = ParagraphSearch::new(&paragraph, 80, "world").unwrap();
+        let lines = paragraph.wrapped_lines(80);
+        let highlighted = search.highlight(&lines, Style::new().bg(Color::Yellow));
+
+        let text: String = highlighted[0]
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect();
+        assert_eq!(text, "hello world");
+
+        let backgrounds: Vec<Option<Color>> = highlighted[0]
+            .styled_graphemes(Style::default())
+            .map(|g| g.style.bg)
+            .collect();
+        let expected: Vec<Option<Color>> = "hello world"
+            .chars()
+            .enumerate()
+            .map(|(i, _)| if i >= 6 { Some(Color::Yellow) } else { None })
+            .collect();
+        assert_eq!(backgrounds, expected);
+    }
+
+    #[test]
+    fn byte_to_column_accounts_for_wide_graphemes() {
+        // "文" is a double-width grapheme, so a plain char count would be off by one.
+        assert_eq!(byte_to_column("文hello", "文".len()), 2);
+    }
+}
@@ -0,0 +1,143 @@
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    prelude::*,
+    widgets::{StatefulWidget, Widget},
+};
+
+/// State for a [`ScrollView`], tracking the current viewport offset into the virtual buffer.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ScrollViewState {
+    /// The `(x, y)` offset of the top-left corner of the viewport within the virtual buffer.
+    pub offset: (u16, u16),
+}
+
+impl ScrollViewState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_offset(mut self, offset: (u16, u16)) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn scroll_up(&mut self, amount: u16) {
+        self.offset.1 = self.offset.1.saturating_sub(amount);
+    }
+
+    pub fn scroll_down(&mut self, amount: u16) {
+        self.offset.1 = self.offset.1.saturating_add(amount);
+    }
+
+    pub fn scroll_left(&mut self, amount: u16) {
+        self.offset.0 = self.offset.0.saturating_sub(amount);
+    }
+
+    pub fn scroll_right(&mut self, amount: u16) {
+        self.offset.0 = self.offset.0.saturating_add(amount);
+    }
+}
+
+/// A widget that renders arbitrary content into a virtual buffer larger than the screen, then
+/// blits the window described by a [`ScrollViewState`] into the target area.
+///
+/// Construct a [`ScrollView`] with the logical size of the content, render widgets into
+/// [`ScrollView::buf_mut`], then render the [`ScrollView`] itself (with a [`ScrollViewState`])
+/// into the real target area. Offsets are clamped to the content size, and a wide grapheme that
+/// only partially falls inside the viewport at the right edge is simply not copied, rather than
+/// copied partially.
+///
+/// A [`ScrollView`] also exposes its content and viewport lengths, via [`ScrollView::content_length`]
+/// and [`ScrollView::viewport_length`], so it can drive a [`Scrollbar`](crate::widgets::Scrollbar)
+/// via [`ScrollbarState`](crate::widgets::ScrollbarState)'s proportional mode.
+#[derive(Debug)]
+pub struct ScrollView {
+    buf: Buffer,
+}
+
+impl ScrollView {
+    /// Creates a new `ScrollView` with a virtual buffer of the given `size`.
+    pub fn new(size: Rect) -> Self {
+        Self {
+            buf: Buffer::empty(size),
+        }
+    }
+
+    /// Returns a mutable reference to the virtual buffer, for rendering widgets into.
+    pub fn buf_mut(&mut self) -> &mut Buffer {
+        &mut self.buf
+    }
+
+    /// Renders `widget` into the virtual buffer at `area`.
+    pub fn render_widget<W: Widget>(&mut self, widget: W, area: Rect) {
+        widget.render(area, &mut self.buf);
+    }
+
+    /// The size of the virtual (logical) content.
+    pub fn content_size(&self) -> Rect {
+        self.buf.area
+    }
+
+    /// The length of the scrollable content along the vertical axis, for use with
+    /// [`ScrollbarState::content_length`](crate::widgets::ScrollbarState::content_length).
+    pub fn content_length(&self) -> usize {
+        self.buf.area.height as usize
+    }
+
+    /// The length of the viewport along the vertical axis once this `ScrollView` is rendered into
+    /// `area`, for use with
+    /// [`ScrollbarState::viewport_content_length`](crate::widgets::ScrollbarState::viewport_content_length).
+    pub fn viewport_length(&self, area: Rect) -> usize {
+        area.height as usize
+    }
+}
+
+impl StatefulWidget for ScrollView {
+    type State = ScrollViewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let content = self.buf.area;
+
+        let max_x_offset = content.width.saturating_sub(area.width);
+        let max_y_offset = content.height.saturating_sub(area.height);
+        state.offset.0 = state.offset.0.min(max_x_offset);
+        state.offset.1 = state.offset.1.min(max_y_offset);
+
+        let visible_width = area.width.min(content.width.saturating_sub(state.offset.0));
+        let visible_height = area
+            .height
+            .min(content.height.saturating_sub(state.offset.1));
+
+        for y in 0..visible_height {
+            for x in 0..visible_width {
+                let src = self.buf.get(content.x + state.offset.0 + x, content.y + state.offset.1 + y);
+                if x + (src.symbol.width() as u16).max(1) > visible_width {
+                    continue;
+                }
+                let dst = buf.get_mut(area.x + x, area.y + y);
+                *dst = src.clone();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::ScrollbarState;
+
+    #[test]
+    fn content_length_and_viewport_length_feed_a_proportional_scrollbar_state() {
+        let scroll_view = ScrollView::new(Rect::new(0, 0, 20, 50));
+        let viewport = Rect::new(0, 0, 20, 10);
+
+        let state = ScrollbarState::new(scroll_view.content_length())
+            .viewport_content_length(scroll_view.viewport_length(viewport));
+
+        assert_eq!(scroll_view.content_length(), 50);
+        assert_eq!(scroll_view.viewport_length(viewport), 10);
+        assert_eq!(state, ScrollbarState::new(50).viewport_content_length(10));
+    }
+}
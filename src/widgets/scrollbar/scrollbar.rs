@@ -74,6 +74,31 @@ pub struct Scrollbar<'a> {
     begin_style: Style,
     end_symbol: Option<&'a str>,
     end_style: Style,
+    rounded: bool,
+    min_thumb_length: usize,
+    visibility: ScrollbarVisibility,
+    smooth: bool,
+    reversed: bool,
+}
+
+/// Eighth-block glyphs used by [`Scrollbar::smooth`] to render a vertical thumb edge at sub-cell
+/// resolution, indexed by how many eighths of the cell (counted from the bottom) are filled.
+const VERTICAL_EIGHTHS: [&str; 9] = [" ", "▁", "▂", "▃", "▄", "▅", "▆", "▇", "█"];
+
+/// Eighth-block glyphs used by [`Scrollbar::smooth`] to render a horizontal thumb edge at
+/// sub-cell resolution, indexed by how many eighths of the cell (counted from the left) are
+/// filled.
+const HORIZONTAL_EIGHTHS: [&str; 9] = [" ", "▏", "▎", "▍", "▌", "▋", "▊", "▉", "█"];
+
+/// Controls when a [`Scrollbar`] renders at all.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ScrollbarVisibility {
+    /// Always render the scrollbar, even when the content fits entirely in the viewport.
+    #[default]
+    Always,
+    /// Skip rendering entirely when the content already fits in the viewport, so a scrollbar
+    /// embedded in a reusable layout disappears instead of showing an empty track.
+    Auto,
 }
 
 /// This is the position of the scrollbar around a given area.
@@ -110,6 +135,11 @@ impl<'a> Default for Scrollbar<'a> {
             begin_style: Style::default(),
             end_symbol: Some(DOUBLE_VERTICAL.end),
             end_style: Style::default(),
+            rounded: false,
+            min_thumb_length: 1,
+            visibility: ScrollbarVisibility::default(),
+            smooth: false,
+            reversed: false,
         }
     }
 }
@@ -311,6 +341,103 @@ impl<'a> Scrollbar<'a> {
         self.end_style = style;
         self
     }
+
+    /// Enables rounded end-caps on the thumb: the first and last cell of the thumb use a
+    /// half-block glyph instead of the regular thumb symbol, giving the thumb a pill-shaped
+    /// silhouette similar to platform scrollbars. Has no visible effect when the thumb is a
+    /// single cell long.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn rounded(mut self, rounded: bool) -> Self {
+        self.rounded = rounded;
+        self
+    }
+
+    /// Sets the minimum length, in cells, that the thumb will be shrunk to.
+    ///
+    /// Without a minimum, a large enough `content_length` relative to the track rounds the thumb
+    /// down to zero cells, making it disappear entirely even though there's more content than fits
+    /// the viewport. Defaults to `1`, so the thumb is always visible; set it higher to make it
+    /// easier to grab with the mouse.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn min_thumb_length(mut self, min_thumb_length: usize) -> Self {
+        self.min_thumb_length = min_thumb_length;
+        self
+    }
+
+    /// Sets whether the scrollbar should hide itself when the content fits the viewport.
+    ///
+    /// Defaults to [`ScrollbarVisibility::Always`]. Set to [`ScrollbarVisibility::Auto`] to skip
+    /// rendering entirely once `state.content_length` no longer exceeds the viewport, which is
+    /// useful when the same `Scrollbar` is rendered unconditionally around a pane whose content
+    /// may or may not overflow.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn visibility(mut self, visibility: ScrollbarVisibility) -> Self {
+        self.visibility = visibility;
+        self
+    }
+
+    /// Renders the thumb's leading and trailing edges at eighth-cell resolution instead of
+    /// snapping them to whole cells.
+    ///
+    /// Without this, a short track can only represent the thumb's position in whole-cell steps,
+    /// which looks jumpy as the content scrolls a little at a time. With `smooth` enabled, the
+    /// fractional remainder of the thumb's start/end position is quantized to eighths and drawn
+    /// with a partial block glyph, so the thumb appears to glide instead of jump. Has no effect on
+    /// [`Scrollbar::rounded`]'s end caps, which take priority over the fractional glyph when both
+    /// are enabled.
+    ///
+    /// Only takes effect when [`Scrollbar::thumb_symbol`] is still one of the solid block glyphs
+    /// (the default, or one set via [`Scrollbar::symbols`]/[`Scrollbar::orientation`]); a custom
+    /// non-block `thumb_symbol` (e.g. `"#"`) has no sub-cell representation, so this falls back to
+    /// the regular whole-cell rendering instead of silently swapping in a different glyph.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn smooth(mut self, smooth: bool) -> Self {
+        self.smooth = smooth;
+        self
+    }
+
+    /// Reverses which end of the track position `0` maps to.
+    ///
+    /// By default, position `0` puts the thumb at the begin symbol and position `content_length`
+    /// puts it at the end symbol. A tail-following view (a log viewer or chat pane that anchors
+    /// content at the bottom and grows upward) wants the opposite: position `0` (the most recent,
+    /// bottom-anchored content) should sit at the far end of the track, moving back towards the
+    /// begin symbol as the position increases. Setting `reversed` mirrors the thumb's placement
+    /// (and [`Scrollbar::position_at`]'s mapping) so callers don't have to invert their own
+    /// position math to get this.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn reversed(mut self, reversed: bool) -> Self {
+        self.reversed = reversed;
+        self
+    }
+
+    /// Whether [`Scrollbar::thumb_symbol`] is one of the solid block glyphs that
+    /// [`Scrollbar::smooth`] knows how to partially fill. Custom symbols (arbitrary text, an emoji,
+    /// `"#"`, ...) have no meaningful sub-cell representation, so `smooth` falls back to whole-cell
+    /// rendering for those instead.
+    fn thumb_symbol_is_block(&self) -> bool {
+        matches!(self.thumb_symbol, "█" | "▓" | "▒" | "░" | "■")
+    }
+
+    /// The glyphs used for the thumb's leading/trailing cell when [`Scrollbar::rounded`] is set,
+    /// oriented so the half-block "rounds off" the cap in the direction the thumb grows.
+    fn cap_symbols(&self) -> (&'static str, &'static str) {
+        if self.orientation.is_vertical() {
+            ("▄", "▀")
+        } else {
+            ("▐", "▌")
+        }
+    }
 }
 
 impl ScrollbarOrientation {
@@ -326,6 +453,11 @@ impl<'a> StatefulWidget for Scrollbar<'a> {
         if state.content_length == 0 {
             return;
         }
+        if self.visibility == ScrollbarVisibility::Auto
+            && state.content_length <= self.viewport_length(area) as usize
+        {
+            return;
+        }
 
         let area = self.scollbar_area(area);
         let bars = self.bars(area, state);
@@ -354,23 +486,201 @@ impl Scrollbar<'_> {
         }
     }
 
+    /// Maps a mouse cell at `(column, row)` to the content position it corresponds to, the
+    /// inverse of [`Scrollbar::part_lengths`]/[`Scrollbar::bars`].
+    ///
+    /// Returns `None` when the cell is outside the scrollbar's area entirely, or lands on one of
+    /// the begin/end arrow heads (which have no corresponding content position). Otherwise returns
+    /// a position clamped to `0..content_length`, suitable for passing straight to
+    /// [`ScrollbarState::position`] in response to a click or drag on the bar.
+    pub fn position_at(&self, area: Rect, state: &ScrollbarState, column: u16, row: u16) -> Option<usize> {
+        let bar_area = self.scollbar_area(area);
+        if column < bar_area.x
+            || column >= bar_area.right()
+            || row < bar_area.y
+            || row >= bar_area.bottom()
+        {
+            return None;
+        }
+
+        let begin_len = self.begin_symbol.map(|s| s.width() as u16).unwrap_or(0);
+        let end_len = self.end_symbol.map(|s| s.width() as u16).unwrap_or(0);
+        let track_len = self.track_length_excluding_arrow_heads(area);
+        if track_len == 0 {
+            return None;
+        }
+
+        let (offset, bar_len) = if self.orientation.is_vertical() {
+            (row - bar_area.y, bar_area.height)
+        } else {
+            (column - bar_area.x, bar_area.width)
+        };
+
+        if offset < begin_len || offset >= bar_len.saturating_sub(end_len) {
+            return None;
+        }
+        let offset_in_track = offset - begin_len;
+
+        // Mirrors whichever formula `part_lengths` used to place the thumb, so a click maps back
+        // to the position that actually produced the thumb placement at that cell.
+        let (position, max_position) = if state.viewport_content_length > 0 {
+            // In proportional mode `state.position` only ever ranges up to the scrollable span
+            // (`content_length - viewport_content_length`), not `content_length - 1`: the thumb
+            // itself occupies the remaining `viewport_content_length` worth of the track.
+            let max_position = state
+                .content_length
+                .saturating_sub(state.viewport_content_length);
+            (
+                self.position_from_proportional_offset(offset_in_track, track_len, state),
+                max_position,
+            )
+        } else {
+            let viewport_len = self.viewport_length(area) as f64;
+            let content_length = state.content_length as f64;
+            let scrollable_content_len = content_length + viewport_len - 1.0;
+            let position =
+                (offset_in_track as f64 * scrollable_content_len / track_len as f64).round() as usize;
+            (position, state.content_length.saturating_sub(1))
+        };
+        let position = position.min(max_position);
+        Some(if self.reversed {
+            max_position - position
+        } else {
+            position
+        })
+    }
+
+    /// Inverts [`Scrollbar::proportional_part_lengths`]'s thumb placement: given the track offset
+    /// a click landed on, returns the content position that would place the thumb's start there.
+    fn position_from_proportional_offset(
+        &self,
+        offset_in_track: u16,
+        track_len: u16,
+        state: &ScrollbarState,
+    ) -> usize {
+        let content_length = state.content_length.max(1) as f64;
+        let viewport_content_length = state.viewport_content_length as f64;
+        let track_len = track_len as f64;
+
+        let thumb_len = ((track_len * viewport_content_length / content_length).round() as usize)
+            .max(1)
+            .min(track_len as usize) as f64;
+
+        let scrollable = content_length - viewport_content_length;
+        let available = track_len - thumb_len;
+        if scrollable > 0.0 && available > 0.0 {
+            (offset_in_track as f64 * scrollable / available).round() as usize
+        } else {
+            0
+        }
+    }
+
     /// Returns an iterator over the symbols and styles of the parts of a scrollbar
     fn bars(&self, area: Rect, state: &mut ScrollbarState) -> impl Iterator<Item = (&str, Style)> {
-        let (track_start_len, thumb_len, track_end_len) = self.part_lengths(area, state);
-
         let begin = self.begin_symbol.map(|s| (s, self.begin_style));
-        let track = self.track_symbol.map(|s| (s, self.track_style));
-        let thumb = Some((self.thumb_symbol, self.thumb_style));
         let end = self.end_symbol.map(|s| (s, self.end_style));
+        let track = self.track_symbol.map(|s| (s, self.track_style));
+
+        let middle: Vec<(&str, Style)> = if self.smooth && self.thumb_symbol_is_block() && !self.rounded {
+            self.smooth_thumb_cells(area, state, track)
+        } else {
+            let (track_start_len, thumb_len, track_end_len) = self.part_lengths(area, state);
+            let thumb_cells: Vec<(&str, Style)> = if self.rounded && thumb_len > 1 {
+                let (start_cap, end_cap) = self.cap_symbols();
+                let mut cells = vec![(start_cap, self.thumb_style)];
+                cells.extend(
+                    iter::repeat((self.thumb_symbol, self.thumb_style)).take(thumb_len - 2),
+                );
+                cells.push((end_cap, self.thumb_style));
+                cells
+            } else {
+                vec![(self.thumb_symbol, self.thumb_style); thumb_len]
+            };
+
+            iter::repeat(track)
+                .take(track_start_len)
+                .chain(thumb_cells.into_iter().map(Some))
+                .chain(iter::repeat(track).take(track_end_len))
+                .flatten()
+                .collect()
+        };
 
         iter::once(begin)
-            .chain(iter::repeat(track).take(track_start_len))
-            .chain(iter::repeat(thumb).take(thumb_len))
-            .chain(iter::repeat(track).take(track_end_len))
+            .chain(middle.into_iter().map(Some))
             .chain(iter::once(end))
             .flatten()
     }
 
+    /// Builds the cells of the track+thumb region (everything between the begin/end arrows) at
+    /// eighth-cell resolution, for [`Scrollbar::smooth`].
+    ///
+    /// The cells strictly inside `[ceil(thumb_start), floor(thumb_end))` are solid thumb cells;
+    /// the one cell at each edge that only partially overlaps the thumb is drawn with a partial
+    /// block glyph sized to the overlap, quantized to eighths.
+    fn smooth_thumb_cells<'s>(
+        &'s self,
+        area: Rect,
+        state: &mut ScrollbarState,
+        track: Option<(&'s str, Style)>,
+    ) -> Vec<(&'s str, Style)> {
+        let track_len = self.track_length_excluding_arrow_heads(area) as usize;
+        let (thumb_start, thumb_end) = self.fractional_thumb_bounds(area, state);
+        let eighths = if self.orientation.is_vertical() {
+            &VERTICAL_EIGHTHS
+        } else {
+            &HORIZONTAL_EIGHTHS
+        };
+
+        let solid_start = thumb_start.ceil() as usize;
+        let solid_end = (thumb_end.floor() as usize).max(solid_start);
+
+        (0..track_len)
+            .map(|cell| {
+                let cell_f = cell as f64;
+                if cell >= solid_start && cell < solid_end {
+                    (self.thumb_symbol, self.thumb_style)
+                } else if cell_f < thumb_start && cell_f + 1.0 > thumb_start {
+                    // leading edge: the thumb covers the trailing portion of this cell.
+                    let covered = (cell_f + 1.0 - thumb_start).clamp(0.0, 1.0);
+                    let glyph = eighths[(covered * 8.0).round() as usize];
+                    let mut style = self.track_style;
+                    if let Some(fg) = self.thumb_style.fg {
+                        style = style.fg(fg);
+                    }
+                    (glyph, style)
+                } else if cell_f < thumb_end && cell_f + 1.0 > thumb_end {
+                    // trailing edge: the thumb covers the leading portion of this cell.
+                    let covered = (thumb_end - cell_f).clamp(0.0, 1.0);
+                    let glyph = eighths[(covered * 8.0).round() as usize];
+                    let mut style = self.track_style;
+                    if let Some(fg) = self.thumb_style.fg {
+                        style = style.fg(fg);
+                    }
+                    (glyph, style)
+                } else {
+                    track.unwrap_or((" ", Style::default()))
+                }
+            })
+            .collect()
+    }
+
+    /// Like [`Scrollbar::part_lengths`]'s vscode-style branch, but keeps the thumb's start/end as
+    /// fractional cell offsets instead of rounding them, for [`Scrollbar::smooth`].
+    fn fractional_thumb_bounds(&self, area: Rect, state: &mut ScrollbarState) -> (f64, f64) {
+        let track_len = self.track_length_excluding_arrow_heads(area) as f64;
+        let viewport_len = self.viewport_length(area) as f64;
+        let content_length = state.content_length as f64;
+        let position = state.position.min(state.content_length.saturating_sub(1)) as f64;
+        let scrollable_content_len = content_length + viewport_len - 1.0;
+        let thumb_start = position * track_len / scrollable_content_len;
+        let thumb_end = (position + viewport_len) * track_len / scrollable_content_len;
+        if self.reversed {
+            (track_len - thumb_end, track_len - thumb_start)
+        } else {
+            (thumb_start, thumb_end)
+        }
+    }
+
     /// Returns the lengths of the parts of a scrollbar
     ///
     /// ```plain
@@ -386,6 +696,10 @@ impl Scrollbar<'_> {
         // this will prevent rendering outside of available area
         let position = state.position.min(state.content_length - 1) as f64;
 
+        if state.viewport_content_length > 0 {
+            return self.proportional_part_lengths(track_len, state);
+        }
+
         // vscode style scrolling behavior
         let scrollable_content_len = content_length + viewport_len - 1.0;
         let thumb_start = position * track_len / scrollable_content_len;
@@ -400,10 +714,61 @@ impl Scrollbar<'_> {
         let track_start_len = thumb_start.round() as usize;
         let thumb_end = thumb_end.round() as usize;
 
-        let thumb_len = thumb_end.saturating_sub(track_start_len);
-        let track_end_len = track_len as usize - track_start_len - thumb_len;
+        let thumb_len = thumb_end
+            .saturating_sub(track_start_len)
+            .max(self.min_thumb_length)
+            .min(track_len as usize);
+
+        // the thumb may have grown past its proportional size to satisfy `min_thumb_length`, so
+        // the leading track is clamped to leave room for it and the trailing track is recomputed
+        // from the clamped `thumb_len`, so the three parts still sum to `track_len`.
+        let available = (track_len as usize).saturating_sub(thumb_len);
+        let track_start_len = track_start_len.min(available);
+        let track_end_len = track_len as usize - thumb_len - track_start_len;
+
+        if self.reversed {
+            (track_end_len, thumb_len, track_start_len)
+        } else {
+            (track_start_len, thumb_len, track_end_len)
+        }
+    }
+
+    /// Computes thumb size and position directly proportional to
+    /// `viewport_content_length / content_length`, rather than the continuous vscode-style
+    /// formula `part_lengths` otherwise uses. This gives an exact at-a-glance sense of how much
+    /// content is off-screen when the caller knows the real on-screen item count (as opposed to
+    /// the raw area height, which may not match when items span multiple rows).
+    ///
+    /// The thumb always touches the end of the track once `position` reaches
+    /// `content_length - viewport_content_length` (and stays there for any larger position), since
+    /// `track_start_len` is clamped to `track_len - thumb_len`.
+    fn proportional_part_lengths(
+        &self,
+        track_len: f64,
+        state: &ScrollbarState,
+    ) -> (usize, usize, usize) {
+        let content_length = state.content_length.max(1) as f64;
+        let viewport_content_length = state.viewport_content_length as f64;
+
+        let thumb_len = ((track_len * viewport_content_length / content_length).round() as usize)
+            .max(1)
+            .min(track_len as usize);
+
+        let scrollable = content_length - viewport_content_length;
+        let track_start_len = if scrollable > 0.0 {
+            (((track_len - thumb_len as f64) * state.position as f64 / scrollable).round()
+                as usize)
+                .min(track_len as usize - thumb_len)
+        } else {
+            0
+        };
 
-        (track_start_len, thumb_len, track_end_len)
+        let track_end_len = track_len as usize - track_start_len - thumb_len;
+        if self.reversed {
+            (track_end_len, thumb_len, track_start_len)
+        } else {
+            (track_start_len, thumb_len, track_end_len)
+        }
     }
 
     fn track_length_excluding_arrow_heads(&self, area: Rect) -> u16 {
@@ -767,4 +1132,291 @@ mod tests {
             .map(|(a, b)| format!("{a}{b}"));
         assert_eq!(buffer, Buffer::with_lines(bars), "{}", assertion_message);
     }
+
+    #[test]
+    fn proportional_thumb_is_sized_from_viewport_content_length() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let mut state = ScrollbarState::default()
+            .content_length(20)
+            .viewport_content_length(5)
+            .position(0);
+        Scrollbar::default()
+            .orientation(ScrollbarOrientation::HorizontalTop)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_symbol(Some("-"))
+            .thumb_symbol("#")
+            .render(buffer.area, &mut buffer, &mut state);
+        // track_len(10) * viewport_content_length(5) / content_length(20) = 2.5 -> rounds to 3
+        assert_eq!(buffer, Buffer::with_lines(vec!["###-------"]));
+    }
+
+    #[test]
+    fn reversed_anchors_thumb_to_the_far_end_at_position_zero() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let mut state = ScrollbarState::default()
+            .position(0)
+            .content_length(10);
+        Scrollbar::default()
+            .orientation(ScrollbarOrientation::HorizontalTop)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_symbol(Some("-"))
+            .thumb_symbol("#")
+            .reversed(true)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(vec!["-----#####"]));
+    }
+
+    #[test]
+    fn smooth_renders_partial_edge_glyphs() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        // viewport_len(10) + content_length(16) - 1 = 25 scrollable units over a 10 cell track:
+        // thumb_start = 0, thumb_end = 10 * 10 / 25 = 4.0 exactly, so this should render as a
+        // whole-cell thumb with no partial glyph.
+        let mut state = ScrollbarState::default().content_length(16).position(0);
+        Scrollbar::default()
+            .orientation(ScrollbarOrientation::HorizontalTop)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_symbol(Some("-"))
+            .thumb_symbol("█")
+            .smooth(true)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(vec!["████------"]));
+
+        // position(1): thumb_start = 1 * 10 / 25 = 0.4, a partial leading cell.
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let mut state = ScrollbarState::default().content_length(16).position(1);
+        Scrollbar::default()
+            .orientation(ScrollbarOrientation::HorizontalTop)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_symbol(Some("-"))
+            .thumb_symbol("█")
+            .smooth(true)
+            .render(buffer.area, &mut buffer, &mut state);
+        // leading cell 0: covered = 1.0 - 0.4 = 0.6 -> round(0.6 * 8) = 5 -> "▋"
+        // trailing cell 4: covered = 4.4 - 4.0 = 0.4 -> round(0.4 * 8) = 3 -> "▍"
+        assert_eq!(buffer, Buffer::with_lines(vec!["▋███▍-----"]));
+    }
+
+    #[test]
+    fn smooth_falls_back_to_whole_cells_for_non_block_thumb_symbol() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        // Same scenario as `smooth_renders_partial_edge_glyphs`'s second case (a partial leading
+        // and trailing cell), but with a custom non-block `thumb_symbol`: since `#` has no eighths
+        // glyphs, `smooth` should render the same whole-cell thumb it would with `smooth` off.
+        let mut state = ScrollbarState::default().content_length(16).position(1);
+        Scrollbar::default()
+            .orientation(ScrollbarOrientation::HorizontalTop)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_symbol(Some("-"))
+            .thumb_symbol("#")
+            .smooth(true)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(vec!["####------"]));
+    }
+
+    #[test]
+    fn auto_visibility_hides_when_content_fits_viewport() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let mut state = ScrollbarState::default().content_length(5);
+        Scrollbar::default()
+            .orientation(ScrollbarOrientation::HorizontalTop)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_symbol(Some("-"))
+            .thumb_symbol("#")
+            .visibility(ScrollbarVisibility::Auto)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(vec![" ".repeat(10)]));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let mut state = ScrollbarState::default().content_length(20);
+        Scrollbar::default()
+            .orientation(ScrollbarOrientation::HorizontalTop)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_symbol(Some("-"))
+            .thumb_symbol("#")
+            .visibility(ScrollbarVisibility::Auto)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_ne!(buffer, Buffer::with_lines(vec![" ".repeat(10)]));
+    }
+
+    #[test]
+    fn position_at_maps_cell_to_content_position() {
+        let area = Rect::new(0, 0, 10, 1);
+        let state = ScrollbarState::default().content_length(10);
+        let scrollbar = Scrollbar::default()
+            .orientation(ScrollbarOrientation::HorizontalTop)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_symbol(Some("-"))
+            .thumb_symbol("#");
+
+        assert_eq!(scrollbar.position_at(area, &state, 0, 0), Some(0));
+        assert_eq!(scrollbar.position_at(area, &state, 9, 0), Some(9));
+        assert_eq!(scrollbar.position_at(area, &state, 20, 0), None);
+        assert_eq!(scrollbar.position_at(area, &state, 0, 5), None);
+    }
+
+    #[test]
+    fn position_at_ignores_arrow_heads() {
+        let area = Rect::new(0, 0, 10, 1);
+        let state = ScrollbarState::default().content_length(10);
+        let scrollbar = Scrollbar::default()
+            .orientation(ScrollbarOrientation::HorizontalTop)
+            .begin_symbol(Some("<"))
+            .end_symbol(Some(">"))
+            .track_symbol(Some("-"))
+            .thumb_symbol("#");
+
+        assert_eq!(scrollbar.position_at(area, &state, 0, 0), None);
+        assert_eq!(scrollbar.position_at(area, &state, 9, 0), None);
+        assert!(scrollbar.position_at(area, &state, 5, 0).is_some());
+    }
+
+    #[test]
+    fn position_at_matches_part_lengths_scrollable_span() {
+        // `position_at` must derive the content position from the same vscode-style
+        // `scrollable_content_len` (`content_length + viewport_len - 1`) that `part_lengths` uses
+        // to place the thumb, or clicks would map back to positions the thumb never actually
+        // visits. With no begin/end arrows the track spans the whole 10-cell area, so the first
+        // and last cell must map to the first and last content position exactly, and the mapping
+        // must be monotonically non-decreasing across the track.
+        let area = Rect::new(0, 0, 10, 1);
+        let state = ScrollbarState::default().content_length(10);
+        let scrollbar = Scrollbar::default()
+            .orientation(ScrollbarOrientation::HorizontalTop)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_symbol(Some("-"))
+            .thumb_symbol("#");
+
+        assert_eq!(scrollbar.position_at(area, &state, 0, 0), Some(0));
+        assert_eq!(scrollbar.position_at(area, &state, 9, 0), Some(9));
+
+        let mut previous = 0;
+        for column in 1..10 {
+            let position = scrollbar.position_at(area, &state, column, 0).unwrap();
+            assert!(position >= previous, "column {column} mapped backwards");
+            previous = position;
+        }
+    }
+
+    #[test]
+    fn position_at_matches_proportional_part_lengths() {
+        // Regression test: with `viewport_content_length` set, `part_lengths` switches to
+        // `proportional_part_lengths`'s thumb placement, which `position_at` must invert instead
+        // of the vscode-style formula, or clicks map back to the wrong content position.
+        let area = Rect::new(0, 0, 10, 1);
+        let state = ScrollbarState::default()
+            .content_length(20)
+            .viewport_content_length(5);
+        let scrollbar = Scrollbar::default()
+            .orientation(ScrollbarOrientation::HorizontalTop)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_symbol(Some("-"))
+            .thumb_symbol("#");
+
+        assert_eq!(scrollbar.position_at(area, &state, 0, 0), Some(0));
+        // The last track cell must map to the maximum scroll position
+        // (`content_length - viewport_content_length`), matching where `proportional_part_lengths`
+        // leaves the thumb touching the track end.
+        assert_eq!(scrollbar.position_at(area, &state, 9, 0), Some(15));
+
+        let mut previous = 0;
+        for column in 1..10 {
+            let position = scrollbar.position_at(area, &state, column, 0).unwrap();
+            assert!(position >= previous, "column {column} mapped backwards");
+            previous = position;
+        }
+    }
+
+    #[test]
+    fn min_thumb_length_keeps_thumb_visible_on_huge_content() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let mut state = ScrollbarState::default()
+            .content_length(10_000)
+            .position(0);
+        Scrollbar::default()
+            .orientation(ScrollbarOrientation::HorizontalTop)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_symbol(Some("-"))
+            .thumb_symbol("#")
+            .min_thumb_length(2)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(vec!["##--------"]));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let mut state = ScrollbarState::default()
+            .content_length(10_000)
+            .position(9_999);
+        Scrollbar::default()
+            .orientation(ScrollbarOrientation::HorizontalTop)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_symbol(Some("-"))
+            .thumb_symbol("#")
+            .min_thumb_length(2)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(vec!["--------##"]));
+    }
+
+    #[test]
+    fn proportional_thumb_touches_track_end_at_max_scroll() {
+        // track_len(10) * viewport_content_length(5) / content_length(20) = 2.5 -> rounds to 3
+        let max_scroll = 20 - 5; // content_length - viewport_content_length
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let mut state = ScrollbarState::default()
+            .content_length(20)
+            .viewport_content_length(5)
+            .position(max_scroll);
+        Scrollbar::default()
+            .orientation(ScrollbarOrientation::HorizontalTop)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_symbol(Some("-"))
+            .thumb_symbol("#")
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(vec!["-------###"]));
+
+        // scrolling further than `max_scroll` must clamp to the same, fully-touching position
+        // rather than pushing the thumb past the end of the track.
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let mut state = ScrollbarState::default()
+            .content_length(20)
+            .viewport_content_length(5)
+            .position(max_scroll + 1000);
+        Scrollbar::default()
+            .orientation(ScrollbarOrientation::HorizontalTop)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_symbol(Some("-"))
+            .thumb_symbol("#")
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(vec!["-------###"]));
+    }
+
+    #[test]
+    fn rounded_caps_replace_thumb_ends() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let mut state = ScrollbarState::default()
+            .content_length(10)
+            .position(0);
+        Scrollbar::default()
+            .orientation(ScrollbarOrientation::HorizontalTop)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_symbol(Some("-"))
+            .thumb_symbol("#")
+            .rounded(true)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(vec!["▐###▌-----"]));
+    }
 }